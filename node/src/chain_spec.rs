@@ -2,7 +2,7 @@ use sp_core::{Pair, Public, sr25519, crypto::UncheckedInto};
 use subsocial_runtime::{
 	AccountId, AuraConfig, BalancesConfig,
 	GenesisConfig, GrandpaConfig, UtilsConfig,
-	SudoConfig, SpacesConfig, SystemConfig,
+	SudoConfig, SpacesConfig, SystemConfig, ScoresConfig, ScoringWeights,
 	WASM_BINARY, Signature, constants::currency::DOLLARS,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -220,6 +220,21 @@ fn testnet_genesis(
 		pallet_spaces: Some(SpacesConfig {
 			endowed_account: root_key,
 		}),
+		pallet_scores: Some(ScoresConfig {
+			action_weights: ScoringWeights {
+				follow_space: 7,
+				follow_account: 3,
+
+				share_post: 7,
+				upvote_post: 5,
+				downvote_post: -3,
+
+				create_comment: 5,
+				share_comment: 5,
+				upvote_comment: 4,
+				downvote_comment: -2,
+			},
+		}),
 	}
 }
 
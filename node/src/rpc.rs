@@ -34,12 +34,17 @@ pub fn create_full<C, P>(
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: notifications_rpc::NotificationsRuntimeApi<Block, AccountId, BlockNumber>,
+    C::Api: post_history_rpc::PostHistoryRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: posts_rpc::PostsRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: profile_follows_rpc::ProfileFollowsRuntimeApi<Block, AccountId>,
+    C::Api: profile_history_rpc::ProfileHistoryRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: profiles_rpc::ProfilesRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: reactions_rpc::ReactionsRuntimeApi<Block, AccountId, BlockNumber>,
-    C::Api: roles_rpc::RolesRuntimeApi<Block, AccountId>,
+    C::Api: roles_rpc::RolesRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: space_follows_rpc::SpaceFollowsRuntimeApi<Block, AccountId>,
+    C::Api: space_history_rpc::SpaceHistoryRuntimeApi<Block, AccountId, BlockNumber>,
+    C::Api: space_ownership_rpc::SpaceOwnershipRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: spaces_rpc::SpacesRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
@@ -47,12 +52,17 @@ pub fn create_full<C, P>(
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 
+    use notifications_rpc::{Notifications, NotificationsApi};
+    use post_history_rpc::{PostHistory, PostHistoryApi};
     use posts_rpc::{Posts, PostsApi};
     use profile_follows_rpc::{ProfileFollows, ProfileFollowsApi};
+    use profile_history_rpc::{ProfileHistory, ProfileHistoryApi};
     use profiles_rpc::{Profiles, ProfilesApi};
     use reactions_rpc::{Reactions, ReactionsApi};
     use roles_rpc::{Roles, RolesApi};
     use space_follows_rpc::{SpaceFollows, SpaceFollowsApi};
+    use space_history_rpc::{SpaceHistory, SpaceHistoryApi};
+    use space_ownership_rpc::{SpaceOwnership, SpaceOwnershipApi};
     use spaces_rpc::{Spaces, SpacesApi};
 
     let mut io = jsonrpc_core::IoHandler::default();
@@ -98,5 +108,25 @@ pub fn create_full<C, P>(
         RolesApi::to_delegate(Roles::new(client.clone()),
     ));
 
+    io.extend_with(
+        PostHistoryApi::to_delegate(PostHistory::new(client.clone()),
+    ));
+
+    io.extend_with(
+        SpaceHistoryApi::to_delegate(SpaceHistory::new(client.clone()),
+    ));
+
+    io.extend_with(
+        ProfileHistoryApi::to_delegate(ProfileHistory::new(client.clone()),
+    ));
+
+    io.extend_with(
+        SpaceOwnershipApi::to_delegate(SpaceOwnership::new(client.clone()),
+    ));
+
+    io.extend_with(
+        NotificationsApi::to_delegate(Notifications::new(client.clone()),
+    ));
+
     io
 }
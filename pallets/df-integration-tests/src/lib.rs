@@ -2,8 +2,9 @@
 mod tests {
     use frame_support::{
         assert_ok, assert_noop,
-        impl_outer_origin, parameter_types,
-        weights::Weight,
+        impl_outer_origin, impl_outer_dispatch, parameter_types,
+        traits::{OnInitialize, OnRuntimeUpgrade, Filter},
+        weights::{Weight, GetDispatchInfo},
         dispatch::DispatchResult,
         storage::StorageMap,
     };
@@ -14,6 +15,7 @@ mod tests {
         testing::Header,
         Perbill,
         Storage,
+        DispatchError,
     };
     use frame_system::{self as system};
 
@@ -22,18 +24,23 @@ mod tests {
         SpacePermission as SP,
         SpacePermissions,
     };
-    use pallet_posts::{Post, PostUpdate, PostExtension, Comment, Error as PostsError};
-    use pallet_profiles::{ProfileUpdate, Error as ProfilesError};
+    use pallet_posts::{Post, PostUpdate, PostExtension, Comment, Tag, Mention, Error as PostsError, rpc::{FeedOptions, FlatPostKind}};
+    use pallet_post_history::{EditHistory, OldPostHistoryRecord, OldPostUpdate};
+    use pallet_post_ownership::Error as PostOwnershipError;
+    use pallet_profiles::{ProfileUpdate, SocialAccountById, Error as ProfilesError};
     use pallet_profile_follows::Error as ProfileFollowsError;
     use pallet_reactions::{ReactionId, ReactionKind, PostReactionScores, Error as ReactionsError};
-    use pallet_scores::ScoringAction;
+    use pallet_roles::{RoleUpdate, Error as RolesError};
+    use pallet_scores::{ScoringAction, ScoringWeights, Error as ScoresError, MAX_REPUTATION_DIFFS_TO_MIGRATE_PER_BLOCK};
     use pallet_spaces::{SpaceById, SpaceUpdate, Error as SpacesError};
     use pallet_space_follows::Error as SpaceFollowsError;
     use pallet_space_ownership::Error as SpaceOwnershipError;
-    use pallet_moderation::{EntityId, EntityStatus, ReportId};
+    use pallet_moderation::{EntityId, EntityStatus, ReportId, Error as ModerationError};
+    use pallet_notifications::{NotificationKind, NotificationTarget, Error as NotificationsError};
     use pallet_utils::{
         mock_functions::*,
         Error as UtilsError, Module as Utils,
+        ContentValidator, DefaultContentValidator,
         SpaceId, PostId, User, Content,
     };
 
@@ -41,6 +48,15 @@ mod tests {
         pub enum Origin for TestRuntime {}
     }
 
+    impl_outer_dispatch! {
+        pub enum Call for TestRuntime where origin: Origin {
+            frame_system::System,
+            pallet_posts::Posts,
+            pallet_spaces::Spaces,
+            pallet_space_follows::SpaceFollows,
+        }
+    }
+
     #[derive(Clone, Eq, PartialEq)]
     pub struct TestRuntime;
 
@@ -54,7 +70,7 @@ mod tests {
     impl system::Trait for TestRuntime {
         type BaseCallFilter = ();
         type Origin = Origin;
-        type Call = ();
+        type Call = Call;
         type Index = u64;
         type BlockNumber = u64;
         type Hash = H256;
@@ -107,6 +123,8 @@ mod tests {
     parameter_types! {
       pub const MinHandleLen: u32 = 5;
       pub const MaxHandleLen: u32 = 50;
+      pub const MaxRawContentLen: u32 = 64;
+      pub const RawContentDepositPerByte: u64 = 1;
     }
 
     impl pallet_utils::Trait for TestRuntime {
@@ -114,6 +132,26 @@ mod tests {
         type Currency = Balances;
         type MinHandleLen = MinHandleLen;
         type MaxHandleLen = MaxHandleLen;
+        type MaxRawContentLen = MaxRawContentLen;
+        type RawContentDepositPerByte = RawContentDepositPerByte;
+        type ContentValidator = TestContentValidator;
+    }
+
+    /// Accepts hex-encoded hypercore keys in addition to content supported by default.
+    pub struct TestContentValidator;
+    impl ContentValidator for TestContentValidator {
+        fn validate_content(content: &Content) -> DispatchResult {
+            match content {
+                Content::Hyper(key) => {
+                    if key.len() == 64 && key.iter().all(u8::is_ascii_hexdigit) {
+                        Ok(())
+                    } else {
+                        Err(DispatchError::Other("InvalidHypercoreKey"))
+                    }
+                },
+                _ => DefaultContentValidator::<TestRuntime>::validate_content(content),
+            }
+        }
     }
 
     use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -124,25 +162,44 @@ mod tests {
 
     parameter_types! {
         pub const MaxCommentDepth: u32 = 10;
+        pub const MaxTagsPerPost: u16 = 3;
+        pub const MaxTagLen: u32 = 10;
+        pub const MaxMentionsPerPost: u16 = 3;
+        pub const HotPointsDoublingBlocks: u64 = 7_200;
+        pub const HotPostsWindowBlocks: u64 = 100_800;
     }
 
     impl pallet_posts::Trait for TestRuntime {
         type Event = ();
         type MaxCommentDepth = MaxCommentDepth;
         type PostScores = Scores;
+        type AfterPostCreated = Notifications;
         type AfterPostUpdated = PostHistory;
         type IsPostBlocked = Moderation;
+        type MaxTagsPerPost = MaxTagsPerPost;
+        type MaxTagLen = MaxTagLen;
+        type MaxMentionsPerPost = MaxMentionsPerPost;
+        type HotPointsDoublingBlocks = HotPointsDoublingBlocks;
+        type HotPostsWindowBlocks = HotPostsWindowBlocks;
     }
 
-    parameter_types! {}
+    parameter_types! {
+        pub const MaxPostHistoryRecords: u16 = 3;
+    }
 
-    impl pallet_post_history::Trait for TestRuntime {}
+    impl pallet_post_history::Trait for TestRuntime {
+        type MaxHistoryRecords = MaxPostHistoryRecords;
+    }
+
+    impl pallet_post_ownership::Trait for TestRuntime {
+        type Event = ();
+    }
 
     parameter_types! {}
 
     impl pallet_profile_follows::Trait for TestRuntime {
         type Event = ();
-        type BeforeAccountFollowed = Scores;
+        type BeforeAccountFollowed = (Scores, Notifications);
         type BeforeAccountUnfollowed = Scores;
     }
 
@@ -153,28 +210,37 @@ mod tests {
         type AfterProfileUpdated = ProfileHistory;
     }
 
-    parameter_types! {}
+    parameter_types! {
+        pub const MaxProfileHistoryRecords: u16 = 3;
+    }
 
-    impl pallet_profile_history::Trait for TestRuntime {}
+    impl pallet_profile_history::Trait for TestRuntime {
+        type MaxHistoryRecords = MaxProfileHistoryRecords;
+    }
 
     parameter_types! {}
 
     impl pallet_reactions::Trait for TestRuntime {
         type Event = ();
-        type PostReactionScores = Scores;
+        type PostReactionScores = (Scores, Notifications);
     }
 
     parameter_types! {
         pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+        pub const MaxExpiredRolesToDeletePerBlock: u16 = 20;
+        pub const MaxRolesToClone: u16 = 20;
     }
 
     impl pallet_roles::Trait for TestRuntime {
         type Event = ();
         type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+        type MaxExpiredRolesToDeletePerBlock = MaxExpiredRolesToDeletePerBlock;
+        type MaxRolesToClone = MaxRolesToClone;
         type Spaces = Spaces;
         type SpaceFollows = SpaceFollows;
         type IsAccountBlocked = Moderation;
         type IsContentBlocked = Moderation;
+        type AfterRoleGranted = Notifications;
     }
 
     parameter_types! {
@@ -189,22 +255,13 @@ mod tests {
         pub const ShareCommentActionWeight: i16 = 5;
         pub const UpvoteCommentActionWeight: i16 = 4;
         pub const DownvoteCommentActionWeight: i16 = -2;
+
+        pub const ReputationHalfLife: u64 = 1_000;
     }
 
     impl pallet_scores::Trait for TestRuntime {
         type Event = ();
-
-        type FollowSpaceActionWeight = FollowSpaceActionWeight;
-        type FollowAccountActionWeight = FollowAccountActionWeight;
-
-        type SharePostActionWeight = SharePostActionWeight;
-        type UpvotePostActionWeight = UpvotePostActionWeight;
-        type DownvotePostActionWeight = DownvotePostActionWeight;
-
-        type CreateCommentActionWeight = CreateCommentActionWeight;
-        type ShareCommentActionWeight = ShareCommentActionWeight;
-        type UpvoteCommentActionWeight = UpvoteCommentActionWeight;
-        type DownvoteCommentActionWeight = DownvoteCommentActionWeight;
+        type ReputationHalfLife = ReputationHalfLife;
     }
 
     parameter_types! {}
@@ -215,10 +272,13 @@ mod tests {
         type BeforeSpaceUnfollowed = Scores;
     }
 
-    parameter_types! {}
+    parameter_types! {
+        pub const MaxTransfersExpiringPerBlock: u16 = 2;
+    }
 
     impl pallet_space_ownership::Trait for TestRuntime {
         type Event = ();
+        type MaxTransfersExpiringPerBlock = MaxTransfersExpiringPerBlock;
     }
 
     const HANDLE_DEPOSIT: u64 = 0;
@@ -228,16 +288,79 @@ mod tests {
         type Currency = Balances;
         type Roles = Roles;
         type SpaceFollows = SpaceFollows;
+        type SpaceCoOwners = SpaceMultiOwnership;
         type BeforeSpaceCreated = SpaceFollows;
         type AfterSpaceUpdated = SpaceHistory;
         type IsAccountBlocked = Moderation;
         type IsContentBlocked = Moderation;
         type HandleDeposit = ();
+        type Call = Call;
+        type SpaceActionsFilter = TestSpaceActionsFilter;
+        type CallSpaceResolver = TestCallSpaceResolver;
+        type CallRawContentDetector = TestCallRawContentDetector;
     }
 
-    parameter_types! {}
+    pub struct TestSpaceActionsFilter;
+    impl Filter<Call> for TestSpaceActionsFilter {
+        fn filter(c: &Call) -> bool {
+            !matches!(c, Call::Spaces(..))
+        }
+    }
+
+    pub struct TestCallSpaceResolver;
+    impl pallet_spaces::CallSpaceResolver<Call> for TestCallSpaceResolver {
+        fn resolve_space_id(c: &Call) -> Option<SpaceId> {
+            match c {
+                Call::Posts(pallet_posts::Call::create_post(space_id_opt, ..)) => *space_id_opt,
+                Call::SpaceFollows(pallet_space_follows::Call::follow_space(space_id)) => Some(*space_id),
+                Call::Spaces(pallet_spaces::Call::update_space(space_id, _)) => Some(*space_id),
+                _ => None,
+            }
+        }
+    }
+
+    pub struct TestCallRawContentDetector;
+    impl pallet_spaces::CallRawContentDetector<Call> for TestCallRawContentDetector {
+        fn has_raw_content(c: &Call) -> bool {
+            match c {
+                Call::Posts(pallet_posts::Call::create_post(_, _, content, ..)) => content.is_raw(),
+                Call::Posts(pallet_posts::Call::update_post(_, update)) =>
+                    update.content.as_ref().map_or(false, |content| content.is_raw()),
+                _ => false,
+            }
+        }
+    }
+
+    parameter_types! {
+        pub const MaxSpaceHistoryRecords: u16 = 3;
+    }
+
+    impl pallet_space_history::Trait for TestRuntime {
+        type MaxHistoryRecords = MaxSpaceHistoryRecords;
+    }
+
+    parameter_types! {
+        pub const MinSpaceOwners: u16 = 1;
+        pub const MaxSpaceOwners: u16 = 1000;
+        pub const MaxChangeNotesLength: u16 = 1024;
+        pub const BlocksToLive: u64 = 302_400;
+        pub const DeleteExpiredChangesPeriod: u64 = 1800;
+        pub const ProposalDepositPerByte: u64 = 0;
+    }
 
-    impl pallet_space_history::Trait for TestRuntime {}
+    impl pallet_space_multi_ownership::Trait for TestRuntime {
+        type Event = ();
+        type Call = Call;
+        type CallFilter = ();
+        type CallSpaceResolver = TestCallSpaceResolver;
+        type ProposalDepositPerByte = ProposalDepositPerByte;
+        type Spaces = SpaceOwnership;
+        type MinSpaceOwners = MinSpaceOwners;
+        type MaxSpaceOwners = MaxSpaceOwners;
+        type MaxChangeNotesLength = MaxChangeNotesLength;
+        type BlocksToLive = BlocksToLive;
+        type DeleteExpiredChangesPeriod = DeleteExpiredChangesPeriod;
+    }
 
     parameter_types! {
         pub const DefaultAutoblockThreshold: u16 = 20;
@@ -248,11 +371,21 @@ mod tests {
         type DefaultAutoblockThreshold = DefaultAutoblockThreshold;
     }
 
+    parameter_types! {
+        pub const MaxNotificationsPerAccount: u16 = 3;
+    }
+
+    impl pallet_notifications::Trait for TestRuntime {
+        type Event = ();
+        type MaxNotificationsPerAccount = MaxNotificationsPerAccount;
+    }
+
     type System = system::Module<TestRuntime>;
     type Balances = pallet_balances::Module<TestRuntime>;
 
     type Posts = pallet_posts::Module<TestRuntime>;
     type PostHistory = pallet_post_history::Module<TestRuntime>;
+    type PostOwnership = pallet_post_ownership::Module<TestRuntime>;
     type ProfileFollows = pallet_profile_follows::Module<TestRuntime>;
     type Profiles = pallet_profiles::Module<TestRuntime>;
     type ProfileHistory = pallet_profile_history::Module<TestRuntime>;
@@ -261,9 +394,11 @@ mod tests {
     type Scores = pallet_scores::Module<TestRuntime>;
     type SpaceFollows = pallet_space_follows::Module<TestRuntime>;
     type SpaceHistory = pallet_space_history::Module<TestRuntime>;
+    type SpaceMultiOwnership = pallet_space_multi_ownership::Module<TestRuntime>;
     type SpaceOwnership = pallet_space_ownership::Module<TestRuntime>;
     type Spaces = pallet_spaces::Module<TestRuntime>;
     type Moderation = pallet_moderation::Module<TestRuntime>;
+    type Notifications = pallet_notifications::Module<TestRuntime>;
 
     pub type AccountId = u64;
     type BlockNumber = u64;
@@ -282,6 +417,10 @@ mod tests {
             let _ = pallet_balances::GenesisConfig::<TestRuntime> {
                 balances: accounts.iter().cloned().map(|k|(k, 100)).collect()
             }.assimilate_storage(storage);
+
+            let _ = pallet_scores::GenesisConfig {
+                action_weights: default_scoring_weights(),
+            }.assimilate_storage::<TestRuntime>(storage);
         }
 
         /// Default ext configuration with BlockNumber 1
@@ -467,11 +606,13 @@ mod tests {
         space_id: Option<SpaceId>,
         content: Option<Content>,
         hidden: Option<bool>,
-    ) -> PostUpdate {
+    ) -> PostUpdate<AccountId> {
         PostUpdate {
             space_id,
             content,
             hidden,
+            tags: None,
+            mentions: None,
         }
     }
 
@@ -495,6 +636,22 @@ mod tests {
         ReactionKind::Downvote
     }
 
+    fn default_scoring_weights() -> ScoringWeights {
+        ScoringWeights {
+            follow_space: FollowSpaceActionWeight::get(),
+            follow_account: FollowAccountActionWeight::get(),
+
+            share_post: SharePostActionWeight::get(),
+            upvote_post: UpvotePostActionWeight::get(),
+            downvote_post: DownvotePostActionWeight::get(),
+
+            create_comment: CreateCommentActionWeight::get(),
+            share_comment: ShareCommentActionWeight::get(),
+            upvote_comment: UpvoteCommentActionWeight::get(),
+            downvote_comment: DownvoteCommentActionWeight::get(),
+        }
+    }
+
     fn scoring_action_upvote_post() -> ScoringAction {
         ScoringAction::UpvotePost
     }
@@ -643,13 +800,50 @@ mod tests {
             space_id_opt.unwrap_or(Some(SPACE1)),
             extension.unwrap_or_else(extension_regular_post),
             content.unwrap_or_else(post_content_ipfs),
+            vec![],
+            vec![],
+        )
+    }
+
+    fn _create_post_with_tags(
+        space_id_opt: Option<SpaceId>,
+        extension: PostExtension,
+        tags: Vec<Tag>,
+    ) -> DispatchResult {
+        Posts::create_post(
+            Origin::signed(ACCOUNT1),
+            space_id_opt,
+            extension,
+            post_content_ipfs(),
+            tags,
+            vec![],
+        )
+    }
+
+    fn _create_post_with_mentions(
+        origin: Origin,
+        space_id_opt: Option<SpaceId>,
+        extension: PostExtension,
+        mentions: Vec<Mention<AccountId>>,
+    ) -> DispatchResult {
+        Posts::create_post(
+            origin,
+            space_id_opt,
+            extension,
+            post_content_ipfs(),
+            vec![],
+            mentions,
         )
     }
 
+    fn _update_post_tags(post_id: PostId, tags: Vec<Tag>) -> DispatchResult {
+        _update_post(None, Some(post_id), Some(PostUpdate { tags: Some(tags), ..post_update(None, None, None) }))
+    }
+
     fn _update_post(
         origin: Option<Origin>,
         post_id: Option<PostId>,
-        update: Option<PostUpdate>,
+        update: Option<PostUpdate<AccountId>>,
     ) -> DispatchResult {
         Posts::update_post(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
@@ -703,7 +897,7 @@ mod tests {
     fn _update_comment(
         origin: Option<Origin>,
         post_id: Option<PostId>,
-        update: Option<PostUpdate>,
+        update: Option<PostUpdate<AccountId>>,
     ) -> DispatchResult {
         _update_post(
             origin,
@@ -853,6 +1047,49 @@ mod tests {
         Scores::score_post_on_reaction(account, post, kind)
     }
 
+    fn _create_default_space_owners() -> DispatchResult {
+        SpaceMultiOwnership::create_space_owners(
+            Origin::signed(ACCOUNT1),
+            SPACE1,
+            vec![ACCOUNT1, ACCOUNT2],
+            2,
+        )
+    }
+
+    fn hide_space_call() -> Call {
+        Call::Spaces(pallet_spaces::Call::update_space(SPACE1, space_update(None, None, Some(true))))
+    }
+
+    fn _propose_default_space_call() -> DispatchResult {
+        SpaceMultiOwnership::propose_call(
+            Origin::signed(ACCOUNT1),
+            SPACE1,
+            Box::new(hide_space_call()),
+            b"Hide the space".to_vec(),
+        )
+    }
+
+    fn _confirm_default_space_call() -> DispatchResult {
+        SpaceMultiOwnership::confirm_call(
+            Origin::signed(ACCOUNT2),
+            SPACE1,
+            1,
+            hide_space_call().get_dispatch_info().weight,
+        )
+    }
+
+    fn create_post_in_space_call(space_id: SpaceId) -> Call {
+        Call::Posts(pallet_posts::Call::create_post(Some(space_id), extension_regular_post(), post_content_ipfs(), vec![], vec![]))
+    }
+
+    fn _as_space(origin: Option<Origin>, space_id: Option<SpaceId>, call: Option<Call>) -> DispatchResult {
+        Spaces::as_space(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
+            Box::new(call.unwrap_or_else(|| create_post_in_space_call(SPACE1))),
+        )
+    }
+
     fn _transfer_default_space_ownership() -> DispatchResult {
         _transfer_space_ownership(None, None, None)
     }
@@ -866,6 +1103,23 @@ mod tests {
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             space_id.unwrap_or(SPACE1),
             transfer_to.unwrap_or(ACCOUNT2),
+            None,
+        )
+    }
+
+    fn _transfer_default_space_ownership_expiring_at(expires_at: BlockNumber) -> DispatchResult {
+        SpaceOwnership::transfer_space_ownership(
+            Origin::signed(ACCOUNT1),
+            SPACE1,
+            ACCOUNT2,
+            Some(expires_at),
+        )
+    }
+
+    fn _cancel_pending_ownership(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResult {
+        SpaceOwnership::cancel_pending_ownership(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
         )
     }
 
@@ -895,6 +1149,40 @@ mod tests {
         )
     }
 
+    fn _transfer_default_post_ownership() -> DispatchResult {
+        _transfer_post_ownership(None, None, None)
+    }
+
+    fn _transfer_post_ownership(
+        origin: Option<Origin>,
+        post_id: Option<PostId>,
+        transfer_to: Option<AccountId>,
+    ) -> DispatchResult {
+        PostOwnership::transfer_post_ownership(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            post_id.unwrap_or(POST1),
+            transfer_to.unwrap_or(ACCOUNT2),
+        )
+    }
+
+    fn _accept_default_pending_post_ownership() -> DispatchResult {
+        _accept_pending_post_ownership(None, None)
+    }
+
+    fn _accept_pending_post_ownership(origin: Option<Origin>, post_id: Option<PostId>) -> DispatchResult {
+        PostOwnership::accept_pending_ownership(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+            post_id.unwrap_or(POST1),
+        )
+    }
+
+    fn _reject_pending_post_ownership(origin: Option<Origin>, post_id: Option<PostId>) -> DispatchResult {
+        PostOwnership::reject_pending_ownership(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+            post_id.unwrap_or(POST1),
+        )
+    }
+
     /* ---------------------------------------------------------------------------------------------- */
 
     // TODO: fix copy-paste from pallet_roles
@@ -1820,6 +2108,7 @@ mod tests {
 
             // Check storages
             assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+            assert_eq!(Posts::post_ids_by_owner(ACCOUNT1), vec![POST1]);
             assert_eq!(Posts::next_post_id(), POST2);
 
             // Check whether data stored correctly
@@ -1846,6 +2135,79 @@ mod tests {
         });
     }
 
+    #[test]
+    fn get_posts_by_owner_should_filter_posts_by_kind() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_eq!(Posts::get_post_ids_by_owner(ACCOUNT1), vec![POST1, POST2]);
+
+            let post_ids = |kind_filter: Vec<FlatPostKind>| Posts::get_posts_by_owner(ACCOUNT1, kind_filter, 0, 10)
+                .iter().map(|post| post.id).collect::<Vec<PostId>>();
+
+            assert_eq!(post_ids(vec![]), vec![POST2, POST1]);
+            assert_eq!(post_ids(vec![FlatPostKind::RegularPost]), vec![POST1]);
+            assert_eq!(post_ids(vec![FlatPostKind::Comment]), vec![POST2]);
+            assert!(post_ids(vec![FlatPostKind::SharedPost]).is_empty());
+        });
+    }
+
+    #[test]
+    fn get_posts_by_owner_should_return_empty_page_when_offset_exceeds_posts_count() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_eq!(Posts::get_posts_by_owner(ACCOUNT1, vec![], 1, 10).len(), 1);
+            assert!(Posts::get_posts_by_owner(ACCOUNT1, vec![], 2, 10).is_empty());
+            assert!(Posts::get_posts_by_owner(ACCOUNT1, vec![], 100, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn get_home_feed_should_include_posts_of_followed_accounts() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+            assert!(Posts::get_feed(ACCOUNT2, 0, 10).is_empty());
+
+            let options = FeedOptions { include_followed_accounts: true, ..Default::default() };
+            let feed_post_ids = |options: FeedOptions| Posts::get_home_feed(ACCOUNT2, options, 0, 10)
+                .iter().map(|post| post.id).collect::<Vec<PostId>>();
+
+            assert!(feed_post_ids(FeedOptions::default()).is_empty());
+            assert_eq!(feed_post_ids(options.clone()), vec![POST1]);
+
+            // A post from both a followed space and a followed account should be returned once
+            assert_ok!(_default_follow_space()); // Follow SpaceId 1 by ACCOUNT2
+            assert_eq!(feed_post_ids(options), vec![POST1]);
+        });
+    }
+
+    #[test]
+    fn get_home_feed_should_return_empty_page_when_offset_exceeds_posts_count() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+
+            let options = FeedOptions { include_followed_accounts: true, ..Default::default() };
+            assert!(Posts::get_home_feed(ACCOUNT2, options, 5, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn index_post_ids_by_owner_should_backfill_old_posts_in_batches_without_duplicates() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            use pallet_posts::PostIdsByOwner;
+
+            assert_ok!(_create_post(None, None, None, None)); // PostId 2 by ACCOUNT1
+
+            // Emulate that PostId 1 was created before the index was added
+            PostIdsByOwner::<TestRuntime>::insert(ACCOUNT1, vec![POST2]);
+
+            Posts::index_post_ids_by_owner(1);
+            assert!(!Posts::post_ids_by_owner_indexed());
+            assert_eq!(Posts::get_post_ids_by_owner(ACCOUNT1), vec![POST2, POST1]);
+
+            Posts::on_initialize(2);
+            assert!(Posts::post_ids_by_owner_indexed());
+            assert_eq!(Posts::get_post_ids_by_owner(ACCOUNT1), vec![POST2, POST1]);
+        });
+    }
+
     #[test]
     fn create_post_should_work_when_one_of_roles_is_permitted() {
         ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
@@ -1948,6 +2310,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn update_post_should_remove_oldest_history_records_when_limit_reached() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            let max_records = MaxPostHistoryRecords::get();
+
+            // Toggle post visibility one time more than the history limit
+            for i in 0..=max_records {
+                assert_ok!(_update_post(
+                    None,
+                    None,
+                    Some(post_update(None, None, Some(i % 2 == 0)))
+                ));
+            }
+
+            let edit_history = PostHistory::edit_history(POST1);
+            assert_eq!(edit_history.len(), max_records as usize);
+
+            // The very first record (with `hidden: false`) should be removed
+            assert_eq!(edit_history[0].old_data.hidden, Some(true));
+            assert_eq!(edit_history.last().unwrap().old_data.hidden, Some(max_records % 2 == 1));
+        });
+    }
+
     fn check_if_post_moved_correctly(
         moved_post_id: PostId,
         old_space_id: SpaceId,
@@ -2746,102 +3131,364 @@ mod tests {
         });
     }
 
-//--------------------------------------------------------------------------------------------------
-
     #[test]
-    fn change_space_score_should_work_for_follow_space() {
+    fn set_action_weights_should_work_only_for_new_actions() {
         ExtBuilder::build_with_space().execute_with(|| {
             assert_ok!(_follow_space(
                 Some(Origin::signed(ACCOUNT2)),
                 Some(SPACE1)
             ));
 
+            let new_weights = ScoringWeights { follow_space: 10, ..default_scoring_weights() };
+            assert_ok!(Scores::set_action_weights(Origin::root(), new_weights.clone()));
+            assert_eq!(Scores::action_weights(), new_weights);
+            assert_eq!(Scores::score_diff_for_action(1, scoring_action_follow_space()), 10);
+
+            // Score that was already awarded should stay the same
             assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, FollowSpaceActionWeight::get() as i32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + FollowSpaceActionWeight::get() as u32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
-        });
-    }
 
-    #[test]
-    fn change_space_score_should_work_for_unfollow_space() {
-        ExtBuilder::build_with_space().execute_with(|| {
             assert_ok!(_follow_space(
-                Some(Origin::signed(ACCOUNT2)),
-                Some(SPACE1)
-            ));
-            assert_ok!(_unfollow_space(
-                Some(Origin::signed(ACCOUNT2)),
+                Some(Origin::signed(ACCOUNT3)),
                 Some(SPACE1)
             ));
-
-            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, 0);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, FollowSpaceActionWeight::get() as i32 + 10);
         });
     }
 
     #[test]
-    fn change_space_score_should_work_for_upvote_post() {
-        ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+    fn on_runtime_upgrade_should_set_action_weights_only_when_not_stored() {
+        ExtBuilder::build().execute_with(|| {
+            let new_weights = ScoringWeights { follow_space: 10, ..default_scoring_weights() };
+            pallet_scores::ActionWeights::put(new_weights.clone());
+            <Scores as OnRuntimeUpgrade>::on_runtime_upgrade();
+            assert_eq!(Scores::action_weights(), new_weights);
 
-            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, UpvotePostActionWeight::get() as i32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + UpvotePostActionWeight::get() as u32);
+            pallet_scores::ActionWeights::kill();
+            <Scores as OnRuntimeUpgrade>::on_runtime_upgrade();
+            assert_eq!(Scores::action_weights(), ScoringWeights::pre_genesis_config_weights());
         });
     }
 
     #[test]
-    fn change_space_score_should_work_for_downvote_post() {
-        ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_post_reaction(
-                Some(Origin::signed(ACCOUNT2)),
-                None,
-                Some(reaction_downvote())
-            )); // ReactionId 1
-
-            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, DownvotePostActionWeight::get() as i32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    fn set_action_weights_should_fail_when_not_root() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(
+                Scores::set_action_weights(Origin::signed(ACCOUNT1), ScoringWeights::default()),
+                DispatchError::BadOrigin
+            );
         });
     }
 
-//--------------------------------------------------------------------------------------------------
-
     #[test]
-    fn change_post_score_should_work_for_create_comment() {
-        ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_comment(
-                Some(Origin::signed(ACCOUNT2)),
-                None,
-                None,
-                None
-            )); // PostId 2
-
-            assert_eq!(Posts::post_by_id(POST1).unwrap().score, CreateCommentActionWeight::get() as i32);
-            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, CreateCommentActionWeight::get() as i32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + CreateCommentActionWeight::get() as u32);
-            assert_eq!(Scores::post_score_by_account((ACCOUNT2, POST1, scoring_action_create_comment())), Some(CreateCommentActionWeight::get()));
+    fn set_action_weights_should_fail_when_same_weights_provided() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(
+                Scores::set_action_weights(Origin::root(), default_scoring_weights()),
+                ScoresError::<TestRuntime>::NoUpdatesForActionWeights
+            );
         });
     }
 
     #[test]
-    fn change_post_score_should_work_for_upvote_post() {
-        ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_post_reaction(
+    fn decay_reputation_should_work() {
+        assert_eq!(Scores::decay_reputation(1, 5), 1);
+        assert_eq!(Scores::decay_reputation(101, 0), 101);
+        assert_eq!(Scores::decay_reputation(101, 1), 51);
+        assert_eq!(Scores::decay_reputation(101, 2), 26);
+        assert_eq!(Scores::decay_reputation(101, 40), 1);
+    }
+
+    #[test]
+    fn reputation_should_decay_after_half_life() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
                 Some(Origin::signed(ACCOUNT2)),
-                None,
-                None
+                Some(SPACE1)
             ));
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + FollowSpaceActionWeight::get() as u32);
 
-            assert_eq!(Posts::post_by_id(POST1).unwrap().score, UpvotePostActionWeight::get() as i32);
-            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + UpvotePostActionWeight::get() as u32);
-            assert_eq!(Scores::post_score_by_account((ACCOUNT2, POST1, scoring_action_upvote_post())), Some(UpvotePostActionWeight::get()));
+            // Two half-lives later the reputation gained above the minimum should be quartered
+            System::set_block_number(1 + 2 * ReputationHalfLife::get());
+            assert_eq!(
+                Scores::reputation_with_decay(&ACCOUNT1, 1 + FollowSpaceActionWeight::get() as u32),
+                1 + (FollowSpaceActionWeight::get() / 4) as u32
+            );
+
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT3)),
+                Some(SPACE1)
+            ));
+            assert_eq!(
+                Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation,
+                1 + (FollowSpaceActionWeight::get() / 4) as u32 + FollowSpaceActionWeight::get() as u32
+            );
+            assert_eq!(Scores::last_reputation_decay_at(ACCOUNT1), 1 + 2 * ReputationHalfLife::get());
         });
     }
 
     #[test]
-    fn change_post_score_should_work_for_downvote_post() {
-        ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_post_reaction(
+    fn reputation_should_start_to_decay_from_its_next_change_when_it_never_decayed() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            // Reputation that was gained before the decay was introduced
+            let mut social_account = Profiles::get_or_new_social_account(ACCOUNT1);
+            social_account.reputation = 101;
+            SocialAccountById::<TestRuntime>::insert(ACCOUNT1, social_account);
+
+            System::set_block_number(1 + 2 * ReputationHalfLife::get());
+            assert_eq!(Scores::reputation_with_decay(&ACCOUNT1, 101), 101);
+
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+            assert_eq!(
+                Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation,
+                101 + FollowSpaceActionWeight::get() as u32
+            );
+            assert_eq!(Scores::last_reputation_decay_at(ACCOUNT1), 1 + 2 * ReputationHalfLife::get());
+        });
+    }
+
+    /// Turn the reputation diffs of `subject` into the ones made before `ReputationDiffBySubject` was added.
+    fn _make_reputation_diffs_deprecated(actor: AccountId, subject: AccountId, action: ScoringAction) {
+        let key = (actor, subject, action);
+        let diff = pallet_scores::ReputationDiffByActor::<TestRuntime>::take(&key).unwrap();
+        pallet_scores::AccountReputationDiffByAccount::<TestRuntime>::insert(&key, diff);
+        pallet_scores::ReputationDiffBySubject::<TestRuntime>::remove_prefix(subject);
+        pallet_scores::ReputationDiffsCountBySubject::<TestRuntime>::remove(subject);
+        pallet_scores::ReputationDiffsMigrated::put(false);
+    }
+
+    #[test]
+    fn migrate_reputation_diffs_should_add_old_diffs_to_reputation_diff_by_subject() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+            _make_reputation_diffs_deprecated(ACCOUNT2, ACCOUNT1, scoring_action_follow_space());
+
+            assert_noop!(
+                Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 1),
+                ScoresError::<TestRuntime>::ReputationDiffsNotMigrated
+            );
+
+            Scores::migrate_reputation_diffs(MAX_REPUTATION_DIFFS_TO_MIGRATE_PER_BLOCK);
+            assert!(Scores::reputation_diffs_migrated());
+            assert_eq!(
+                Scores::reputation_diff_by_subject(ACCOUNT1, (ACCOUNT2, scoring_action_follow_space())),
+                FollowSpaceActionWeight::get() as i32
+            );
+            assert_eq!(Scores::reputation_diffs_count_by_subject(ACCOUNT1), 1);
+            assert_eq!(
+                Scores::account_reputation_diff_by_account((ACCOUNT2, ACCOUNT1, scoring_action_follow_space())),
+                Some(FollowSpaceActionWeight::get())
+            );
+
+            assert_ok!(Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 1));
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + FollowSpaceActionWeight::get() as u32);
+        });
+    }
+
+    #[test]
+    fn reverting_not_migrated_reputation_diff_should_not_change_reputation_diff_by_subject() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+            _make_reputation_diffs_deprecated(ACCOUNT2, ACCOUNT1, scoring_action_follow_space());
+
+            assert_ok!(_unfollow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+            assert_eq!(Scores::account_reputation_diff_by_account((ACCOUNT2, ACCOUNT1, scoring_action_follow_space())), None);
+            assert_eq!(Scores::reputation_diffs_count_by_subject(ACCOUNT1), 0);
+
+            Scores::migrate_reputation_diffs(MAX_REPUTATION_DIFFS_TO_MIGRATE_PER_BLOCK);
+            assert_eq!(Scores::reputation_diffs_count_by_subject(ACCOUNT1), 0);
+
+            assert_ok!(Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 0));
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+        });
+    }
+
+    #[test]
+    fn recalculate_reputation_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+
+            // Break the reputation of the space owner
+            let mut social_account = Profiles::social_account_by_id(ACCOUNT1).unwrap();
+            social_account.reputation = 1_000;
+            SocialAccountById::<TestRuntime>::insert(ACCOUNT1, social_account);
+
+            assert_ok!(Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 1));
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + FollowSpaceActionWeight::get() as u32);
+
+            // Recalculated reputation should also decay
+            System::set_block_number(1 + 2 * ReputationHalfLife::get());
+            assert_ok!(Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 1));
+            assert_eq!(
+                Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation,
+                1 + (FollowSpaceActionWeight::get() / 4) as u32
+            );
+        });
+    }
+
+    #[test]
+    fn recalculate_reputation_should_fail_when_not_root() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(
+                Scores::recalculate_reputation(Origin::signed(ACCOUNT1), ACCOUNT1, 0),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn recalculate_reputation_should_replay_repeated_actions() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post(None, None, None, None)); // PostId 2 by ACCOUNT1
+
+            // ACCOUNT2 upvotes both posts of ACCOUNT1 and ACCOUNT3 upvotes one of them
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(POST1), None));
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(POST2), None));
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT3)), Some(POST1), None));
+
+            let expected_reputation = 1 + 3 * UpvotePostActionWeight::get() as u32;
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, expected_reputation);
+            assert_eq!(
+                Scores::reputation_diff_by_subject(ACCOUNT1, (ACCOUNT2, scoring_action_upvote_post())),
+                2 * UpvotePostActionWeight::get() as i32
+            );
+            assert_eq!(Scores::reputation_diffs_count_by_subject(ACCOUNT1), 2);
+
+            // Break the reputation of the post owner
+            let mut social_account = Profiles::social_account_by_id(ACCOUNT1).unwrap();
+            social_account.reputation = 1;
+            SocialAccountById::<TestRuntime>::insert(ACCOUNT1, social_account);
+
+            assert_ok!(Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 2));
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, expected_reputation);
+        });
+    }
+
+    #[test]
+    fn recalculate_reputation_should_fail_when_reputation_diffs_count_is_too_low() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+
+            assert_noop!(
+                Scores::recalculate_reputation(Origin::root(), ACCOUNT1, 0),
+                ScoresError::<TestRuntime>::TooLowReputationDiffsCount
+            );
+        });
+    }
+
+//--------------------------------------------------------------------------------------------------
+
+    #[test]
+    fn change_space_score_should_work_for_follow_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, FollowSpaceActionWeight::get() as i32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + FollowSpaceActionWeight::get() as u32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+        });
+    }
+
+    #[test]
+    fn change_space_score_should_work_for_unfollow_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+            assert_ok!(_unfollow_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE1)
+            ));
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, 0);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+        });
+    }
+
+    #[test]
+    fn change_space_score_should_work_for_upvote_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, UpvotePostActionWeight::get() as i32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + UpvotePostActionWeight::get() as u32);
+        });
+    }
+
+    #[test]
+    fn change_space_score_should_work_for_downvote_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_downvote())
+            )); // ReactionId 1
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, DownvotePostActionWeight::get() as i32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+        });
+    }
+
+//--------------------------------------------------------------------------------------------------
+
+    #[test]
+    fn change_post_score_should_work_for_create_comment() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_comment(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                None,
+                None
+            )); // PostId 2
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().score, CreateCommentActionWeight::get() as i32);
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().score, CreateCommentActionWeight::get() as i32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + CreateCommentActionWeight::get() as u32);
+            assert_eq!(Scores::post_score_by_account((ACCOUNT2, POST1, scoring_action_create_comment())), Some(CreateCommentActionWeight::get()));
+        });
+    }
+
+    #[test]
+    fn change_post_score_should_work_for_upvote_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                None
+            ));
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().score, UpvotePostActionWeight::get() as i32);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + UpvotePostActionWeight::get() as u32);
+            assert_eq!(Scores::post_score_by_account((ACCOUNT2, POST1, scoring_action_upvote_post())), Some(UpvotePostActionWeight::get()));
+        });
+    }
+
+    #[test]
+    fn change_post_score_should_work_for_downvote_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(
                 Some(Origin::signed(ACCOUNT2)),
                 None,
                 Some(reaction_downvote())
@@ -3509,12 +4156,32 @@ mod tests {
             assert_ok!(_default_follow_space()); // Follow SpaceId 1 by ACCOUNT2
 
             assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 2);
-            assert_eq!(SpaceFollows::spaces_followed_by_account(ACCOUNT2), vec![SPACE1]);
-            assert_eq!(SpaceFollows::space_followers(SPACE1), vec![ACCOUNT1, ACCOUNT2]);
+            assert_eq!(SpaceFollows::get_space_ids_followed_by_account(ACCOUNT2), vec![SPACE1]);
+            assert!(SpaceFollows::space_follower(SPACE1, ACCOUNT1));
+            assert!(SpaceFollows::space_follower(SPACE1, ACCOUNT2));
             assert_eq!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)), true);
         });
     }
 
+    #[test]
+    fn get_space_followers_should_return_followers_by_pages() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_default_follow_space()); // Follow SpaceId 1 by ACCOUNT2
+
+            let mut followers = SpaceFollows::get_space_followers(SPACE1, 0, 10);
+            followers.sort();
+            assert_eq!(followers, vec![ACCOUNT1, ACCOUNT2]);
+
+            let first_page = SpaceFollows::get_space_followers(SPACE1, 0, 1);
+            let second_page = SpaceFollows::get_space_followers(SPACE1, 1, 1);
+            assert_eq!(first_page.len(), 1);
+            assert_eq!(second_page.len(), 1);
+            assert_ne!(first_page, second_page);
+
+            assert!(SpaceFollows::get_space_followers(SPACE1, 2, 10).is_empty());
+        });
+    }
+
     #[test]
     fn follow_space_should_fail_when_space_not_found() {
         ExtBuilder::build().execute_with(|| {
@@ -3552,8 +4219,8 @@ mod tests {
             assert_ok!(_default_unfollow_space());
 
             assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 1);
-            assert!(SpaceFollows::spaces_followed_by_account(ACCOUNT2).is_empty());
-            assert_eq!(SpaceFollows::space_followers(SPACE1), vec![ACCOUNT1]);
+            assert!(SpaceFollows::get_space_ids_followed_by_account(ACCOUNT2).is_empty());
+            assert_eq!(SpaceFollows::get_space_followers(SPACE1, 0, 10), vec![ACCOUNT1]);
         });
     }
 
@@ -3578,8 +4245,8 @@ mod tests {
         ExtBuilder::build().execute_with(|| {
             assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
 
-            assert_eq!(ProfileFollows::accounts_followed_by_account(ACCOUNT2), vec![ACCOUNT1]);
-            assert_eq!(ProfileFollows::account_followers(ACCOUNT1), vec![ACCOUNT2]);
+            assert_eq!(ProfileFollows::get_accounts_followed_by_account(ACCOUNT2, 0, 10), vec![ACCOUNT1]);
+            assert_eq!(ProfileFollows::get_account_followers(ACCOUNT1, 0, 10), vec![ACCOUNT2]);
             assert_eq!(ProfileFollows::account_followed_by_account((ACCOUNT2, ACCOUNT1)), true);
         });
     }
@@ -3610,8 +4277,8 @@ mod tests {
             // Follow ACCOUNT1 by ACCOUNT2
             assert_ok!(_default_unfollow_account());
 
-            assert!(ProfileFollows::accounts_followed_by_account(ACCOUNT2).is_empty());
-            assert!(ProfileFollows::account_followers(ACCOUNT1).is_empty());
+            assert!(ProfileFollows::get_accounts_followed_by_account(ACCOUNT2, 0, 10).is_empty());
+            assert!(ProfileFollows::get_account_followers(ACCOUNT1, 0, 10).is_empty());
             assert_eq!(ProfileFollows::account_followed_by_account((ACCOUNT2, ACCOUNT1)), false);
         });
     }
@@ -3636,6 +4303,109 @@ mod tests {
         });
     }
 
+    #[test]
+    fn migrate_space_followers_to_double_maps_should_work_in_batches() {
+        ExtBuilder::build().execute_with(|| {
+            use pallet_space_follows::{SpaceFollowers, SpacesFollowedByAccount, SpaceFollowedByAccount};
+
+            SpaceFollowedByAccount::<TestRuntime>::insert((ACCOUNT2, SPACE1), true);
+            SpaceFollowedByAccount::<TestRuntime>::insert((ACCOUNT3, SPACE1), true);
+            SpaceFollowedByAccount::<TestRuntime>::insert((ACCOUNT2, SPACE2), true);
+            SpaceFollowers::<TestRuntime>::insert(SPACE1, vec![ACCOUNT2, ACCOUNT3]);
+            SpaceFollowers::<TestRuntime>::insert(SPACE2, vec![ACCOUNT2]);
+            SpacesFollowedByAccount::<TestRuntime>::insert(ACCOUNT2, vec![SPACE1, SPACE2]);
+            SpacesFollowedByAccount::<TestRuntime>::insert(ACCOUNT3, vec![SPACE1]);
+
+            SpaceFollows::migrate_followers_to_double_maps(3);
+            assert!(!SpaceFollows::followers_migrated());
+
+            SpaceFollows::on_initialize(2);
+            assert!(SpaceFollows::followers_migrated());
+
+            assert!(!SpaceFollowers::<TestRuntime>::contains_key(SPACE1));
+            assert!(!SpaceFollowers::<TestRuntime>::contains_key(SPACE2));
+            assert!(!SpacesFollowedByAccount::<TestRuntime>::contains_key(ACCOUNT2));
+            assert!(!SpacesFollowedByAccount::<TestRuntime>::contains_key(ACCOUNT3));
+
+            let mut followers = SpaceFollows::get_space_followers(SPACE1, 0, 10);
+            followers.sort();
+            assert_eq!(followers, vec![ACCOUNT2, ACCOUNT3]);
+            assert_eq!(SpaceFollows::get_space_followers(SPACE2, 0, 10), vec![ACCOUNT2]);
+            assert!(SpaceFollows::followed_space(ACCOUNT2, SPACE2));
+            assert!(SpaceFollows::followed_space(ACCOUNT3, SPACE1));
+            assert!(!SpaceFollows::followed_space(ACCOUNT3, SPACE2));
+        });
+    }
+
+    #[test]
+    fn migrate_space_followers_to_double_maps_should_split_long_lists_and_skip_unfollowed_accounts() {
+        ExtBuilder::build().execute_with(|| {
+            use pallet_space_follows::{SpaceFollowers, SpaceFollowedByAccount};
+
+            // ACCOUNT3 has unfollowed the space before its entry was migrated
+            SpaceFollowedByAccount::<TestRuntime>::insert((ACCOUNT2, SPACE1), true);
+            SpaceFollowers::<TestRuntime>::insert(SPACE1, vec![ACCOUNT2, ACCOUNT3]);
+
+            SpaceFollows::migrate_followers_to_double_maps(1);
+            assert!(!SpaceFollows::followers_migrated());
+            assert_eq!(SpaceFollowers::<TestRuntime>::get(SPACE1), vec![ACCOUNT3]);
+            assert_eq!(SpaceFollows::get_space_followers(SPACE1, 0, 10), vec![ACCOUNT2]);
+
+            SpaceFollows::migrate_followers_to_double_maps(10);
+            assert!(SpaceFollows::followers_migrated());
+            assert!(!SpaceFollowers::<TestRuntime>::contains_key(SPACE1));
+            assert_eq!(SpaceFollows::get_space_followers(SPACE1, 0, 10), vec![ACCOUNT2]);
+            assert!(!SpaceFollows::space_follower(SPACE1, ACCOUNT3));
+        });
+    }
+
+    #[test]
+    fn migrate_account_followers_to_double_maps_should_skip_unfollowed_accounts() {
+        ExtBuilder::build().execute_with(|| {
+            use pallet_profile_follows::{AccountFollowers, AccountsFollowedByAccount, AccountFollowedByAccount};
+
+            // ACCOUNT3 has unfollowed ACCOUNT1 before its entries were migrated
+            AccountFollowedByAccount::<TestRuntime>::insert((ACCOUNT2, ACCOUNT1), true);
+            AccountFollowers::<TestRuntime>::insert(ACCOUNT1, vec![ACCOUNT2, ACCOUNT3]);
+            AccountsFollowedByAccount::<TestRuntime>::insert(ACCOUNT3, vec![ACCOUNT1]);
+
+            ProfileFollows::migrate_followers_to_double_maps(10);
+            assert!(ProfileFollows::followers_migrated());
+
+            assert_eq!(ProfileFollows::get_account_followers(ACCOUNT1, 0, 10), vec![ACCOUNT2]);
+            assert!(ProfileFollows::get_accounts_followed_by_account(ACCOUNT3, 0, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn migrate_account_followers_to_double_maps_should_work_in_batches() {
+        ExtBuilder::build().execute_with(|| {
+            use pallet_profile_follows::{AccountFollowers, AccountsFollowedByAccount, AccountFollowedByAccount};
+
+            AccountFollowedByAccount::<TestRuntime>::insert((ACCOUNT2, ACCOUNT1), true);
+            AccountFollowedByAccount::<TestRuntime>::insert((ACCOUNT3, ACCOUNT1), true);
+            AccountFollowers::<TestRuntime>::insert(ACCOUNT1, vec![ACCOUNT2, ACCOUNT3]);
+            AccountsFollowedByAccount::<TestRuntime>::insert(ACCOUNT2, vec![ACCOUNT1]);
+            AccountsFollowedByAccount::<TestRuntime>::insert(ACCOUNT3, vec![ACCOUNT1]);
+
+            ProfileFollows::migrate_followers_to_double_maps(2);
+            assert!(!ProfileFollows::followers_migrated());
+
+            ProfileFollows::on_initialize(2);
+            assert!(ProfileFollows::followers_migrated());
+
+            assert!(!AccountFollowers::<TestRuntime>::contains_key(ACCOUNT1));
+            assert!(!AccountsFollowedByAccount::<TestRuntime>::contains_key(ACCOUNT2));
+            assert!(!AccountsFollowedByAccount::<TestRuntime>::contains_key(ACCOUNT3));
+
+            let mut followers = ProfileFollows::get_account_followers(ACCOUNT1, 0, 10);
+            followers.sort();
+            assert_eq!(followers, vec![ACCOUNT2, ACCOUNT3]);
+            assert_eq!(ProfileFollows::get_accounts_followed_by_account(ACCOUNT2, 0, 10), vec![ACCOUNT1]);
+            assert_eq!(ProfileFollows::get_accounts_followed_by_account(ACCOUNT3, 0, 10), vec![ACCOUNT1]);
+        });
+    }
+
 // Transfer ownership tests
 
     #[test]
@@ -3786,4 +4556,1077 @@ mod tests {
             ), SpaceOwnershipError::<TestRuntime>::NotAllowedToRejectOwnershipTransfer); // Rejecting a transfer from ACCOUNT2
         });
     }
+
+    #[test]
+    fn cancel_pending_ownership_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership_expiring_at(10));
+            assert_ok!(_cancel_pending_ownership(None, None)); // Canceling a transfer by ACCOUNT1
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().owner, ACCOUNT1);
+            assert!(SpaceOwnership::pending_space_owner(SPACE1).is_none());
+            assert!(SpaceOwnership::pending_transfer_expires_at(SPACE1).is_none());
+            assert!(SpaceOwnership::pending_transfers_expiring_at(10).is_empty());
+        });
+    }
+
+    #[test]
+    fn cancel_pending_ownership_should_fail_when_account_is_not_space_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership());
+
+            assert_noop!(_cancel_pending_ownership(
+                Some(Origin::signed(ACCOUNT2)),
+                None
+            ), SpaceOwnershipError::<TestRuntime>::NotAllowedToCancelOwnershipTransfer);
+        });
+    }
+
+    #[test]
+    fn cancel_pending_ownership_should_fail_when_no_pending_transfer_on_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _cancel_pending_ownership(None, None),
+                SpaceOwnershipError::<TestRuntime>::NoPendingTransferOnSpace
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_space_ownership_should_fail_when_expiry_is_in_past() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _transfer_default_space_ownership_expiring_at(1),
+                SpaceOwnershipError::<TestRuntime>::TransferExpiryInPast
+            );
+        });
+    }
+
+    #[test]
+    fn expired_pending_ownership_should_be_removed_on_initialize() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership_expiring_at(10));
+
+            let transfers = SpaceOwnership::get_pending_transfers_to_account(ACCOUNT2);
+            assert_eq!(transfers.len(), 1);
+            assert_eq!(transfers[0].space_id, SPACE1);
+            assert_eq!(transfers[0].current_owner, ACCOUNT1);
+            assert_eq!(transfers[0].expires_at, Some(10));
+
+            SpaceOwnership::on_initialize(9);
+            assert_eq!(SpaceOwnership::pending_space_owner(SPACE1), Some(ACCOUNT2));
+
+            System::set_block_number(10);
+            SpaceOwnership::on_initialize(10);
+            assert!(SpaceOwnership::pending_space_owner(SPACE1).is_none());
+            assert!(SpaceOwnership::pending_transfer_expires_at(SPACE1).is_none());
+            assert!(SpaceOwnership::get_pending_transfers_to_account(ACCOUNT2).is_empty());
+
+            assert_noop!(_accept_default_pending_ownership(), SpaceOwnershipError::<TestRuntime>::NoPendingTransferOnSpace);
+        });
+    }
+
+    #[test]
+    fn transfer_space_ownership_should_fail_when_too_many_transfers_expire_at_block() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 2
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 3
+
+            assert_ok!(_transfer_default_space_ownership_expiring_at(10));
+            assert_ok!(SpaceOwnership::transfer_space_ownership(Origin::signed(ACCOUNT1), SPACE2, ACCOUNT2, Some(10)));
+
+            assert_noop!(
+                SpaceOwnership::transfer_space_ownership(Origin::signed(ACCOUNT1), SPACE2 + 1, ACCOUNT2, Some(10)),
+                SpaceOwnershipError::<TestRuntime>::TooManyTransfersExpiringAtBlock
+            );
+            assert_ok!(SpaceOwnership::transfer_space_ownership(Origin::signed(ACCOUNT1), SPACE2 + 1, ACCOUNT2, Some(11)));
+        });
+    }
+
+    #[test]
+    fn overwriting_pending_ownership_should_replace_its_expiry() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership_expiring_at(10));
+            assert_ok!(_transfer_default_space_ownership());
+
+            assert!(SpaceOwnership::pending_transfer_expires_at(SPACE1).is_none());
+            assert!(SpaceOwnership::pending_transfers_expiring_at(10).is_empty());
+
+            SpaceOwnership::on_initialize(10);
+            assert_eq!(SpaceOwnership::pending_space_owner(SPACE1), Some(ACCOUNT2));
+        });
+    }
+
+    // Post ownership tests
+
+    #[test]
+    fn transfer_post_ownership_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_transfer_default_post_ownership()); // Transfer PostId 1 owned by ACCOUNT1 to ACCOUNT2
+
+            assert_eq!(PostOwnership::pending_post_owner(POST1).unwrap(), ACCOUNT2);
+        });
+    }
+
+    #[test]
+    fn transfer_post_ownership_should_fail_when_post_not_found() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(_transfer_default_post_ownership(), PostsError::<TestRuntime>::PostNotFound);
+        });
+    }
+
+    #[test]
+    fn transfer_post_ownership_should_fail_when_account_is_not_post_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(_transfer_post_ownership(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(ACCOUNT3)
+            ), PostsError::<TestRuntime>::NotAPostOwner);
+        });
+    }
+
+    #[test]
+    fn transfer_post_ownership_should_fail_when_trying_to_transfer_to_current_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(_transfer_post_ownership(
+                None,
+                None,
+                Some(ACCOUNT1)
+            ), PostOwnershipError::<TestRuntime>::CannotTransferToCurrentOwner);
+        });
+    }
+
+    #[test]
+    fn accept_pending_post_ownership_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_transfer_default_post_ownership());
+            assert_ok!(_accept_default_pending_post_ownership()); // Accepting a transfer from ACCOUNT2
+
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.owner, ACCOUNT2);
+
+            assert!(PostOwnership::pending_post_owner(POST1).is_none());
+            assert!(Posts::post_ids_by_owner(ACCOUNT1).is_empty());
+            assert_eq!(Posts::post_ids_by_owner(ACCOUNT2), vec![POST1]);
+        });
+    }
+
+    #[test]
+    fn accept_pending_post_ownership_should_fail_when_no_pending_transfer_for_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _accept_default_pending_post_ownership(),
+                PostOwnershipError::<TestRuntime>::NoPendingTransferOnPost
+            );
+        });
+    }
+
+    #[test]
+    fn accept_pending_post_ownership_should_fail_if_origin_is_not_equal_to_pending_account() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_transfer_default_post_ownership());
+
+            assert_noop!(_accept_pending_post_ownership(
+                Some(Origin::signed(ACCOUNT3)),
+                None
+            ), PostOwnershipError::<TestRuntime>::NotAllowedToAcceptOwnershipTransfer);
+        });
+    }
+
+    #[test]
+    fn reject_pending_post_ownership_should_work_by_recipient_and_current_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_transfer_default_post_ownership());
+            assert_ok!(_reject_pending_post_ownership(None, None)); // Rejecting a transfer from ACCOUNT2
+            assert!(PostOwnership::pending_post_owner(POST1).is_none());
+
+            assert_ok!(_transfer_default_post_ownership());
+            assert_ok!(_reject_pending_post_ownership(Some(Origin::signed(ACCOUNT1)), None));
+            assert!(PostOwnership::pending_post_owner(POST1).is_none());
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().owner, ACCOUNT1);
+        });
+    }
+
+    #[test]
+    fn reject_pending_post_ownership_should_fail_when_account_is_not_allowed_to_reject() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_transfer_default_post_ownership());
+
+            assert_noop!(_reject_pending_post_ownership(
+                Some(Origin::signed(ACCOUNT3)),
+                None
+            ), PostOwnershipError::<TestRuntime>::NotAllowedToRejectOwnershipTransfer);
+        });
+    }
+
+    // Space multi-ownership tests
+
+    #[test]
+    fn create_space_owners_should_pass_space_to_space_account() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_space_owners());
+
+            let space_account = SpaceMultiOwnership::space_account_id(SPACE1);
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().owner, space_account);
+            assert!(Spaces::space_ids_by_owner(ACCOUNT1).is_empty());
+            assert_eq!(Spaces::space_ids_by_owner(space_account), vec![SPACE1]);
+        });
+    }
+
+    #[test]
+    fn space_co_owner_should_not_be_able_to_update_space_alone() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_space_owners());
+
+            // Owner actions of a multi-owned space should go through a call proposal.
+            for co_owner in &[ACCOUNT1, ACCOUNT2] {
+                assert_noop!(_update_space(
+                    Some(Origin::signed(*co_owner)),
+                    None,
+                    Some(space_update(None, None, Some(true)))
+                ), SpacesError::<TestRuntime>::NoPermissionToUpdateSpace);
+            }
+        });
+    }
+
+    #[test]
+    fn space_co_owner_should_have_space_owner_permissions_that_are_not_owner_only() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_default_space_owners());
+
+            assert_ok!(_update_post(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(post_update(None, None, Some(true)))
+            ));
+            assert!(Posts::post_by_id(POST1).unwrap().hidden);
+
+            assert_noop!(_update_post(
+                Some(Origin::signed(ACCOUNT3)),
+                None,
+                Some(post_update(None, None, Some(false)))
+            ), PostsError::<TestRuntime>::NoPermissionToUpdateAnyPost);
+
+            // Role management is owner-only, so it should go through a call proposal.
+            assert_noop!(
+                _create_role(Some(Origin::signed(ACCOUNT2)), None, None, None, None),
+                RolesError::<TestRuntime>::NoPermissionToManageRoles
+            );
+        });
+    }
+
+    #[test]
+    fn create_space_owners_should_remove_pending_ownership_transfer() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership_expiring_at(10));
+            assert_ok!(_create_default_space_owners());
+
+            assert!(SpaceOwnership::pending_space_owner(SPACE1).is_none());
+            assert!(SpaceOwnership::pending_transfers_expiring_at(10).is_empty());
+            assert_noop!(_accept_default_pending_ownership(), SpaceOwnershipError::<TestRuntime>::NoPendingTransferOnSpace);
+        });
+    }
+
+    #[test]
+    fn multi_owned_space_account_should_be_space_representative_account() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_eq!(SpaceMultiOwnership::space_account_id(SPACE1), Spaces::space_representative_account_id(SPACE1));
+        });
+    }
+
+    #[test]
+    fn space_co_owner_should_not_be_able_to_transfer_space_ownership_alone() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_space_owners());
+
+            assert_noop!(
+                _transfer_default_space_ownership(),
+                SpacesError::<TestRuntime>::NotASpaceOwner
+            );
+        });
+    }
+
+    #[test]
+    fn confirmed_call_proposal_should_update_space_on_behalf_of_space_account() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_space_owners());
+
+            assert_ok!(_propose_default_space_call());
+            assert!(!Spaces::space_by_id(SPACE1).unwrap().hidden);
+
+            assert_ok!(_confirm_default_space_call());
+
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert!(space.hidden);
+            assert_eq!(space.updated.unwrap().account, SpaceMultiOwnership::space_account_id(SPACE1));
+        });
+    }
+
+    // Acting on behalf of a space tests
+
+    fn _grant_space_role_to_represented_space(permissions: Vec<SpacePermission>) -> DispatchResult {
+        _create_role(None, None, None, None, Some(permissions))?;
+        _grant_role(None, None, Some(vec![User::Space(SPACE1)]))
+    }
+
+    fn update_post_call(post_id: PostId, update: PostUpdate<AccountId>) -> Call {
+        Call::Posts(pallet_posts::Call::update_post(post_id, update))
+    }
+
+    #[test]
+    fn space_owner_should_create_post_on_behalf_of_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_grant_space_role_to_represented_space(vec![SP::CreatePosts]));
+            assert_ok!(_as_space(None, None, None));
+
+            let space_account = Spaces::space_representative_account_id(SPACE1);
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.created.account, space_account);
+            assert_eq!(post.space_id, Some(SPACE1));
+            assert_eq!(Spaces::space_id_by_representative_account(space_account), Some(SPACE1));
+        });
+    }
+
+    #[test]
+    fn account_with_external_representation_role_should_follow_another_space_as_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, Some(None), None, None)); // SPACE2
+            assert_ok!(_create_role(None, None, None, None, Some(vec![SP::RepresentSpaceExternally])));
+            assert_ok!(_grant_default_role());
+
+            assert_ok!(_as_space(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(Call::SpaceFollows(pallet_space_follows::Call::follow_space(SPACE2)))
+            ));
+            let space_account = Spaces::space_representative_account_id(SPACE1);
+            assert!(SpaceFollows::space_followed_by_account((space_account, SPACE2)));
+
+            // Creating a post within the represented space requires internal representation.
+            assert_noop!(
+                _as_space(Some(Origin::signed(ACCOUNT2)), None, None),
+                SpacesError::<TestRuntime>::NoPermissionToRepresentSpace
+            );
+        });
+    }
+
+    #[test]
+    fn as_space_should_fail_when_account_has_no_permission_to_represent_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _as_space(Some(Origin::signed(ACCOUNT2)), None, None),
+                SpacesError::<TestRuntime>::NoPermissionToRepresentSpace
+            );
+        });
+    }
+
+    #[test]
+    fn space_representative_should_not_create_post_without_space_role() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            // The call itself fails, but `as_space` only reports its result in an event.
+            assert_ok!(_as_space(None, None, None));
+            assert!(Posts::post_by_id(POST1).is_none());
+        });
+    }
+
+    #[test]
+    fn space_representative_should_not_update_or_hide_posts_of_other_users() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_post()); // POST1 by ACCOUNT1, the space owner
+            assert_ok!(_grant_space_role_to_represented_space(vec![SP::CreatePosts]));
+
+            assert_ok!(_as_space(None, None, Some(update_post_call(
+                POST1,
+                post_update(None, Some(updated_post_content()), None)
+            ))));
+            assert_ok!(_as_space(None, None, Some(update_post_call(
+                POST1,
+                post_update(None, None, Some(true))
+            ))));
+
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.content, post_content_ipfs());
+            assert!(!post.hidden);
+            assert!(post.updated.is_none());
+        });
+    }
+
+    #[test]
+    fn space_co_owner_should_act_on_behalf_of_space_without_rights_of_space_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_post()); // POST1 by ACCOUNT1
+            assert_ok!(_create_role(
+                None,
+                None,
+                None,
+                None,
+                Some(vec![SP::CreatePosts, SP::RepresentSpaceInternally])
+            ));
+            assert_ok!(_grant_role(None, None, Some(vec![User::Space(SPACE1), User::Account(ACCOUNT1)])));
+            assert_ok!(_create_default_space_owners());
+
+            // The space account owns the space now, but it has no rights of the space owner
+            // when it acts on behalf of the space.
+            assert_ok!(_as_space(None, None, Some(update_post_call(
+                POST1,
+                post_update(None, None, Some(true))
+            ))));
+            assert!(!Posts::post_by_id(POST1).unwrap().hidden);
+
+            assert_ok!(_as_space(None, None, None)); // POST2
+            let space_account = Spaces::space_representative_account_id(SPACE1);
+            assert_eq!(Posts::post_by_id(POST2).unwrap().created.account, space_account);
+            assert!(Spaces::represented_space_id().is_none());
+        });
+    }
+
+    #[test]
+    fn as_space_should_fail_when_call_has_raw_content() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_grant_space_role_to_represented_space(vec![SP::CreatePosts]));
+
+            assert_noop!(
+                _as_space(None, None, Some(Call::Posts(pallet_posts::Call::create_post(
+                    Some(SPACE1),
+                    extension_regular_post(),
+                    raw_content(10),
+                    vec![],
+                    vec![]
+                )))),
+                SpacesError::<TestRuntime>::RawContentIsNotSupportedForSpace
+            );
+        });
+    }
+
+    #[test]
+    fn as_space_should_fail_when_call_is_not_allowed() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _as_space(None, None, Some(hide_space_call())),
+                SpacesError::<TestRuntime>::CallIsNotAllowedForSpace
+            );
+        });
+    }
+
+    // Raw content tests
+
+    fn raw_content(len: usize) -> Content {
+        Content::Raw(vec![b'a'; len])
+    }
+
+    #[test]
+    fn create_post_with_raw_content_should_reserve_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_create_post(None, None, None, Some(raw_content(10))));
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().content, raw_content(10));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 10);
+            assert_eq!(Posts::content_deposit_by_post_id(POST1), 10);
+        });
+    }
+
+    #[test]
+    fn create_space_should_not_reserve_content_deposit_when_it_fails() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_space(None, Some(Some(space_handle())), Some(raw_content(10)), None),
+                SpacesError::<TestRuntime>::SpaceHandleIsNotUnique
+            );
+        });
+    }
+
+    #[test]
+    fn report_entity_should_fail_with_raw_reason() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _report_entity(None, None, None, Some(raw_content(10))),
+                ModerationError::<TestRuntime>::RawReasonIsNotSupported
+            );
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_when_raw_content_is_too_long() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_post(None, None, None, Some(raw_content(MaxRawContentLen::get() as usize + 1))),
+                UtilsError::<TestRuntime>::RawContentIsTooLong
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_should_release_deposit_of_replaced_raw_content() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+            assert_ok!(_create_post(None, None, None, Some(raw_content(10))));
+
+            assert_ok!(_update_post(None, None, Some(post_update(None, Some(raw_content(4)), None))));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 4);
+
+            assert_ok!(_update_post(None, None, Some(post_update(None, Some(post_content_ipfs()), None))));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+        });
+    }
+
+    #[test]
+    fn accept_post_ownership_should_move_raw_content_deposit_to_new_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+            assert_ok!(_create_post(None, None, None, Some(raw_content(10))));
+
+            assert_ok!(_transfer_default_post_ownership());
+            assert_ok!(_accept_default_pending_post_ownership());
+
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 10);
+        });
+    }
+
+    #[test]
+    fn delete_role_should_release_raw_content_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_create_role(None, None, None, Some(raw_content(8)), None));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 8);
+
+            assert_ok!(_delete_default_role());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+        });
+    }
+
+    #[test]
+    fn update_space_should_reserve_raw_content_deposit_from_editor() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_role(None, None, None, None, Some(vec![SP::UpdateSpace])));
+            assert_ok!(_grant_default_role()); // Grant the role to ACCOUNT2
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_update_space(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(space_update(None, Some(raw_content(10)), None))
+            ));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 10);
+            assert_eq!(Spaces::content_deposit_by_space_id(SPACE1), Some((ACCOUNT2, 10)));
+
+            // The deposit of the replaced content is released to the editor that reserved it.
+            assert_ok!(_update_space(None, None, Some(space_update(None, Some(raw_content(4)), None))));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 4);
+        });
+    }
+
+    #[test]
+    fn update_role_should_reserve_raw_content_deposit_from_role_manager() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_role(None, None, None, Some(raw_content(8)), Some(vec![SP::ManageRoles])));
+            assert_ok!(_grant_default_role()); // Grant the role to ACCOUNT2
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(Roles::update_role(
+                Origin::signed(ACCOUNT2),
+                ROLE1,
+                RoleUpdate {
+                    disabled: None,
+                    content: Some(raw_content(6)),
+                    permissions: None,
+                    expires_at: None,
+                }
+            ));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before - 8);
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 6);
+
+            assert_ok!(_delete_default_role());
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+        });
+    }
+
+    // Content validator tests
+
+    fn hyper_content() -> Content {
+        Content::Hyper(b"7b4a7ca2c7e5ad9e1b6e8f7e2f3b0d5a9c1e4f6a8b2d3c5e7f9a1b3c5d7e9f0a".to_vec())
+    }
+
+    #[test]
+    fn create_post_should_accept_content_allowed_by_runtime_validator() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post(None, None, None, Some(hyper_content())));
+            assert_eq!(Posts::post_by_id(POST1).unwrap().content, hyper_content());
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_with_content_rejected_by_runtime_validator() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_post(None, None, None, Some(Content::Hyper(b"not_a_hypercore_key".to_vec()))),
+                DispatchError::Other("InvalidHypercoreKey")
+            );
+        });
+    }
+
+    // Post tags tests
+
+    fn tag(tag: &[u8]) -> Tag {
+        tag.to_vec()
+    }
+
+    #[test]
+    fn create_post_should_index_normalized_tags() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_with_tags(
+                Some(SPACE1),
+                extension_regular_post(),
+                vec![tag(b"Rust"), tag(b"web_3"), tag(b"rust")]
+            ));
+
+            assert_eq!(Posts::tags_by_post_id(POST1), vec![tag(b"rust"), tag(b"web_3")]);
+            assert_eq!(Posts::post_ids_by_tag(tag(b"rust")), vec![POST1]);
+            assert_eq!(Posts::post_ids_by_tag_in_space(SPACE1, tag(b"web_3")), vec![POST1]);
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_with_too_many_tags() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let tags = vec![tag(b"a"), tag(b"b"), tag(b"c"), tag(b"d")];
+            assert_noop!(
+                _create_post_with_tags(Some(SPACE1), extension_regular_post(), tags),
+                PostsError::<TestRuntime>::TooManyTagsOnPost
+            );
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_with_invalid_tag() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"")]),
+                PostsError::<TestRuntime>::TagIsEmpty
+            );
+            assert_noop!(
+                _create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"tag_too_long")]),
+                PostsError::<TestRuntime>::TagIsTooLong
+            );
+            assert_noop!(
+                _create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"no-dash")]),
+                PostsError::<TestRuntime>::TagContainsInvalidChars
+            );
+        });
+    }
+
+    #[test]
+    fn create_comment_should_fail_with_tags() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _create_post_with_tags(None, extension_comment(None, POST1), vec![tag(b"rust")]),
+                PostsError::<TestRuntime>::CannotTagComment
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_should_reindex_tags() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_update_post_tags(POST1, vec![tag(b"Substrate")]));
+
+            assert_eq!(Posts::tags_by_post_id(POST1), vec![tag(b"substrate")]);
+            assert!(Posts::post_ids_by_tag(tag(b"rust")).is_empty());
+            assert!(Posts::post_ids_by_tag_in_space(SPACE1, tag(b"rust")).is_empty());
+            assert_eq!(Posts::post_ids_by_tag(tag(b"substrate")), vec![POST1]);
+            assert_eq!(Posts::post_ids_by_tag_in_space(SPACE1, tag(b"substrate")), vec![POST1]);
+
+            // Check that old tags are saved in a post history
+            let history = PostHistory::edit_history(POST1);
+            assert_eq!(history[0].old_data.tags, Some(vec![tag(b"rust")]));
+
+            assert_ok!(_update_post_tags(POST1, vec![]));
+            assert!(Posts::tags_by_post_id(POST1).is_empty());
+            assert!(Posts::post_ids_by_tag(tag(b"substrate")).is_empty());
+        });
+    }
+
+    #[test]
+    fn untagging_post_should_keep_order_of_other_tagged_posts() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+
+            assert_ok!(_update_post_tags(POST1, vec![]));
+
+            assert_eq!(Posts::posts_count_by_tag(tag(b"rust")), 3);
+            assert_eq!(Posts::post_ids_by_tag(tag(b"rust")), vec![POST2, 3]);
+            assert_eq!(Posts::post_index_by_tag(tag(b"rust"), 3), Some(2));
+            assert!(Posts::post_index_by_tag(tag(b"rust"), POST1).is_none());
+            assert!(Posts::post_id_by_tag_and_index(tag(b"rust"), 0).is_none());
+
+            // A tagged again post takes a new slot, as it is the latest tagged one now.
+            assert_ok!(_update_post_tags(POST1, vec![tag(b"rust")]));
+            assert_eq!(Posts::post_ids_by_tag(tag(b"rust")), vec![POST2, 3, POST1]);
+        });
+    }
+
+    #[test]
+    fn migrate_edit_history_should_prepend_old_records_to_post_history() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            let old_record = OldPostHistoryRecord::<TestRuntime> {
+                edited: pallet_utils::WhoAndWhen::<TestRuntime>::new(ACCOUNT1),
+                old_data: OldPostUpdate { space_id: None, content: Some(post_content_ipfs()), hidden: None },
+            };
+            EditHistory::<TestRuntime>::insert(POST1, vec![old_record]);
+            assert_ok!(_update_post_tags(POST1, vec![tag(b"rust")]));
+
+            PostHistory::migrate_edit_history(1);
+            assert!(!PostHistory::edit_history_migrated());
+
+            let history = PostHistory::edit_history(POST1);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].old_data.content, Some(post_content_ipfs()));
+            assert_eq!(history[0].old_data.tags, None);
+            assert_eq!(history[1].old_data.tags, Some(vec![]));
+            assert!(!EditHistory::<TestRuntime>::contains_key(POST1));
+
+            PostHistory::migrate_edit_history(1);
+            assert!(PostHistory::edit_history_migrated());
+        });
+    }
+
+    #[test]
+    fn move_post_should_move_tags_between_space_indexes() {
+        ExtBuilder::build_with_reacted_post_and_two_spaces().execute_with(|| {
+            assert_ok!(_update_post_tags(POST1, vec![tag(b"rust")]));
+            assert_ok!(_move_post_1_to_space_2());
+
+            assert!(Posts::post_ids_by_tag_in_space(SPACE1, tag(b"rust")).is_empty());
+            assert_eq!(Posts::post_ids_by_tag_in_space(SPACE2, tag(b"rust")), vec![POST1]);
+            assert_eq!(Posts::post_ids_by_tag(tag(b"rust")), vec![POST1]);
+
+            // A post moved out of any space is not indexed by its tags anymore
+            assert_ok!(_move_post_to_nowhere(POST1));
+            assert!(Posts::post_ids_by_tag_in_space(SPACE2, tag(b"rust")).is_empty());
+            assert!(Posts::post_ids_by_tag(tag(b"rust")).is_empty());
+            assert_eq!(Posts::tags_by_post_id(POST1), vec![tag(b"rust")]);
+        });
+    }
+
+    #[test]
+    fn get_posts_by_tag_should_return_public_posts_in_descending_order() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_create_post_with_tags(Some(SPACE1), extension_regular_post(), vec![tag(b"rust")]));
+            assert_ok!(_update_post(None, Some(POST2), Some(post_update(None, None, Some(true)))));
+
+            let post_ids: Vec<PostId> = Posts::get_posts_by_tag(tag(b"RUST"), 0, 10)
+                .iter()
+                .map(|post| post.id)
+                .collect();
+            assert_eq!(post_ids, vec![3, POST1]);
+
+            let post_ids: Vec<PostId> = Posts::get_posts_by_tag(tag(b"rust"), 1, 10)
+                .iter()
+                .map(|post| post.id)
+                .collect();
+            assert_eq!(post_ids, vec![POST1]);
+        });
+    }
+
+    // Mentions tests
+
+    fn _create_post_mentioning(mentions: Vec<Mention<AccountId>>) -> DispatchResult {
+        _create_post_with_mentions(Origin::signed(ACCOUNT1), Some(SPACE1), extension_regular_post(), mentions)
+    }
+
+    #[test]
+    fn create_post_should_index_mentions_of_accounts() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_mentioning(vec![
+                Mention::Account(ACCOUNT2),
+                Mention::Account(ACCOUNT2),
+                Mention::Account(ACCOUNT1),
+            ]));
+
+            assert_eq!(
+                Posts::mentions_by_post_id(POST1),
+                vec![Mention::Account(ACCOUNT2), Mention::Account(ACCOUNT1)]
+            );
+
+            // An author is not notified about their own mention
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT1), 0);
+
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT2), 1);
+            let mention = Posts::mention_by_account(ACCOUNT2, 0).unwrap();
+            assert_eq!(mention.post_id, POST1);
+            assert_eq!(mention.created.account, ACCOUNT1);
+            assert_eq!(mention.space_id, None);
+        });
+    }
+
+    #[test]
+    fn create_comment_should_notify_owner_of_mentioned_space() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_with_mentions(
+                Origin::signed(ACCOUNT2),
+                None,
+                extension_comment(None, POST1),
+                vec![Mention::Space(SPACE1)]
+            ));
+
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT1), 1);
+            let mention = Posts::mention_by_account(ACCOUNT1, 0).unwrap();
+            assert_eq!(mention.post_id, POST2);
+            assert_eq!(mention.created.account, ACCOUNT2);
+            assert_eq!(mention.space_id, Some(SPACE1));
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_with_invalid_mentions() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_post_mentioning(vec![Mention::Account(999)]),
+                PostsError::<TestRuntime>::MentionedAccountNotFound
+            );
+            assert_noop!(
+                _create_post_mentioning(vec![Mention::Space(SPACE2)]),
+                PostsError::<TestRuntime>::MentionedSpaceNotFound
+            );
+            assert_noop!(
+                _create_post_mentioning(vec![Mention::Account(ACCOUNT2); 4]),
+                PostsError::<TestRuntime>::TooManyMentionsInPost
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_should_notify_only_newly_mentioned_accounts() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_mentioning(vec![Mention::Account(ACCOUNT2)]));
+
+            let mentions = vec![Mention::Account(ACCOUNT2), Mention::Account(ACCOUNT3)];
+            assert_ok!(_update_post(
+                None,
+                None,
+                Some(PostUpdate { mentions: Some(mentions.clone()), ..post_update(None, None, None) })
+            ));
+
+            assert_eq!(Posts::mentions_by_post_id(POST1), mentions);
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT2), 1);
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT3), 1);
+
+            // Check that old mentions are saved in a post history
+            let history = PostHistory::edit_history(POST1);
+            assert_eq!(history[0].old_data.mentions, Some(vec![Mention::Account(ACCOUNT2)]));
+        });
+    }
+
+    #[test]
+    fn update_post_should_not_notify_account_mentioned_again() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_mentioning(vec![Mention::Account(ACCOUNT2)]));
+            assert!(Posts::account_mentioned_in_post(POST1, ACCOUNT2));
+
+            let update_mentions = |mentions: Vec<Mention<AccountId>>| _update_post(
+                None,
+                None,
+                Some(PostUpdate { mentions: Some(mentions), ..post_update(None, None, None) })
+            );
+            assert_ok!(update_mentions(vec![]));
+            assert_ok!(update_mentions(vec![Mention::Account(ACCOUNT2)]));
+
+            assert_eq!(Posts::mentions_by_post_id(POST1), vec![Mention::Account(ACCOUNT2)]);
+            assert_eq!(Posts::mentions_count_by_account(ACCOUNT2), 1);
+        });
+    }
+
+    #[test]
+    fn get_mentions_should_return_latest_mentions_first() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_post_mentioning(vec![Mention::Account(ACCOUNT2)]));
+            assert_ok!(_create_post_mentioning(vec![Mention::Account(ACCOUNT2)]));
+            assert_ok!(_create_post_mentioning(vec![Mention::Account(ACCOUNT2)]));
+
+            let post_ids_of_mentions = |offset: u64, limit: u16| -> Vec<PostId> {
+                Posts::get_mentions(ACCOUNT2, offset, limit)
+                    .iter()
+                    .map(|mention| mention.post_id)
+                    .collect()
+            };
+
+            assert_eq!(post_ids_of_mentions(0, 2), vec![3, POST2]);
+            assert_eq!(post_ids_of_mentions(2, 2), vec![POST1]);
+            assert!(post_ids_of_mentions(3, 2).is_empty());
+        });
+    }
+
+    // Notifications tests
+
+    fn notification_kinds(account: AccountId) -> Vec<NotificationKind> {
+        Notifications::get_notifications(account, 0, 10)
+            .into_iter()
+            .map(|notification| notification.kind)
+            .collect()
+    }
+
+    /// Send 4 notifications to ACCOUNT1: two follows, a reply and an upvote.
+    fn notify_account1_four_times() {
+        assert_ok!(_follow_account(Some(Origin::signed(ACCOUNT2)), None));
+        assert_ok!(_follow_account(Some(Origin::signed(ACCOUNT3)), None));
+        assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+        assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT3)), None, None));
+    }
+
+    #[test]
+    fn follow_account_should_notify_followed_account() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_default_follow_account()); // ACCOUNT2 follows ACCOUNT1
+
+            let notifications = Notifications::get_notifications(ACCOUNT1, 0, 10);
+            assert_eq!(notifications.len(), 1);
+            assert_eq!(notifications[0].index, 0);
+            assert_eq!(notifications[0].kind, NotificationKind::AccountFollowed);
+            assert_eq!(notifications[0].actor, ACCOUNT2);
+            assert_eq!(notifications[0].target, NotificationTarget::Account(ACCOUNT1));
+            assert!(!notifications[0].is_read);
+
+            assert!(Notifications::get_notifications(ACCOUNT2, 0, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn create_comment_should_notify_owner_of_parent_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            // An author is not notified about their own comments
+            assert_ok!(_create_default_comment());
+            assert!(notification_kinds(ACCOUNT1).is_empty());
+
+            assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 3
+
+            let notifications = Notifications::get_notifications(ACCOUNT1, 0, 10);
+            assert_eq!(notifications.len(), 1);
+            assert_eq!(notifications[0].kind, NotificationKind::PostReplied);
+            assert_eq!(notifications[0].actor, ACCOUNT2);
+            assert_eq!(notifications[0].target, NotificationTarget::Post(POST3));
+        });
+    }
+
+    #[test]
+    fn share_post_should_notify_owner_of_original_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(b"space2_handle".to_vec())),
+                None,
+                None
+            )); // SpaceId 2 by ACCOUNT2
+
+            assert_ok!(_create_post(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            )); // PostId 2 shares PostId 1
+
+            let notifications = Notifications::get_notifications(ACCOUNT1, 0, 10);
+            assert_eq!(notifications.len(), 1);
+            assert_eq!(notifications[0].kind, NotificationKind::PostShared);
+            assert_eq!(notifications[0].target, NotificationTarget::Post(POST2));
+        });
+    }
+
+    #[test]
+    fn only_new_post_reactions_should_notify_post_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+            assert_ok!(Reactions::update_post_reaction(
+                Origin::signed(ACCOUNT2),
+                POST1,
+                REACTION1,
+                reaction_downvote()
+            ));
+            assert_ok!(Reactions::delete_post_reaction(Origin::signed(ACCOUNT2), POST1, REACTION1));
+
+            assert_eq!(notification_kinds(ACCOUNT1), vec![NotificationKind::PostUpvoted]);
+        });
+    }
+
+    #[test]
+    fn grant_role_should_notify_only_new_role_users() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_role());
+            assert_ok!(_grant_default_role());
+            assert_ok!(_grant_default_role());
+
+            let notifications = Notifications::get_notifications(ACCOUNT2, 0, 10);
+            assert_eq!(notifications.len(), 1);
+            assert_eq!(notifications[0].kind, NotificationKind::RoleGranted);
+            assert_eq!(notifications[0].actor, ACCOUNT1);
+            assert_eq!(notifications[0].target, NotificationTarget::Role(ROLE1));
+        });
+    }
+
+    #[test]
+    fn notifications_should_overwrite_the_oldest_one_when_inbox_is_full() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            notify_account1_four_times();
+
+            assert_eq!(Notifications::notifications_count_by_account(ACCOUNT1), 4);
+            assert_eq!(notification_kinds(ACCOUNT1), vec![
+                NotificationKind::PostUpvoted,
+                NotificationKind::PostReplied,
+                NotificationKind::AccountFollowed,
+            ]);
+
+            let indexes: Vec<u64> = Notifications::get_notifications(ACCOUNT1, 1, 10)
+                .iter()
+                .map(|notification| notification.index)
+                .collect();
+            assert_eq!(indexes, vec![2, 1]);
+
+            assert_eq!(Notifications::get_unread_notifications_count(ACCOUNT1), 3);
+        });
+    }
+
+    #[test]
+    fn mark_notifications_as_read_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            notify_account1_four_times();
+
+            assert_ok!(Notifications::mark_notifications_as_read(Origin::signed(ACCOUNT1), 2));
+
+            let read_flags: Vec<bool> = Notifications::get_notifications(ACCOUNT1, 0, 10)
+                .iter()
+                .map(|notification| notification.is_read)
+                .collect();
+            assert_eq!(read_flags, vec![false, false, true]);
+            assert_eq!(Notifications::get_unread_notifications_count(ACCOUNT1), 2);
+        });
+    }
+
+    #[test]
+    fn mark_notifications_as_read_should_fail_with_invalid_read_count() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            notify_account1_four_times();
+
+            assert_noop!(
+                Notifications::mark_notifications_as_read(Origin::signed(ACCOUNT1), 5),
+                NotificationsError::<TestRuntime>::NotificationNotFound
+            );
+
+            assert_ok!(Notifications::mark_notifications_as_read(Origin::signed(ACCOUNT1), 2));
+            assert_noop!(
+                Notifications::mark_notifications_as_read(Origin::signed(ACCOUNT1), 2),
+                NotificationsError::<TestRuntime>::NotificationsAlreadyRead
+            );
+        });
+    }
 }
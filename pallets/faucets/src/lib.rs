@@ -7,6 +7,12 @@
 //! But this can be changed in the future to allow anyone else
 //! to set up new faucets for their needs.
 //!
+//! A faucet account can drip tokens by itself with a signed `drip` extrinsic.
+//! Alternatively, any user can submit an unsigned `request_drip` transaction
+//! with a small proof of work made on a recent block hash. Such requests are validated in `ValidateUnsigned`,
+//! queued and then processed in `on_initialize` of the next blocks,
+//! so there is no need to keep a faucet key on an always-on web service.
+//!
 //! This would allow each space to create its own faucet(s) and distribute its tokens to its 
 //! members based on a set of conditions the space decides suits the needs of its community.
 
@@ -18,11 +24,15 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     traits::{Currency, ExistenceRequirement, Get},
-    weights::Pays,
+    weights::{Pays, Weight},
 };
-use frame_system::{self as system, ensure_root, ensure_signed};
+use frame_system::{self as system, ensure_none, ensure_root, ensure_signed};
 use sp_runtime::RuntimeDebug;
-use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::traits::{Hash, One, Saturating, Zero};
+use sp_runtime::transaction_validity::{
+    InvalidTransaction, TransactionPriority, TransactionSource,
+    TransactionValidity, ValidTransaction,
+};
 use sp_std::{
     collections::btree_set::BTreeSet,
     iter::FromIterator,
@@ -66,8 +76,27 @@ pub trait Trait: system::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type Currency: Currency<Self::AccountId>;
+
+    /// The number of leading zero bits required in a proof of work of a drip request.
+    type DripRequestDifficulty: Get<u32>;
+
+    /// Max number of drip requests that can wait in a queue to be processed.
+    type MaxPendingDripRequests: Get<u16>;
+
+    /// Max number of drip requests processed in `on_initialize` of a single block.
+    type MaxDripsPerBlock: Get<u16>;
+
+    /// A priority of unsigned `request_drip` transactions in a transaction pool.
+    type DripRequestUnsignedPriority: Get<TransactionPriority>;
 }
 
+/// For how many blocks an unsigned drip request stays valid in a transaction pool.
+const DRIP_REQUEST_LONGEVITY: u64 = 64;
+
+/// Max age (in blocks) of a block which hash is used in a proof of work of a drip request.
+/// Should be less than `BlockHashCount` of the runtime, so the hash is still stored on chain.
+const DRIP_REQUEST_PROOF_MAX_AGE: u32 = 64;
+
 decl_storage! {
     trait Store for Module<T: Trait> as FaucetsModule {
 
@@ -75,6 +104,23 @@ decl_storage! {
         pub FaucetByAccount get(fn faucet_by_account):
             map hasher(twox_64_concat) T::AccountId // Faucet account
             => Option<Faucet<T>>;
+
+        /// A block number when a recipient requested a drip from a faucet last time.
+        /// A record is removed once a faucet period passes after the request.
+        pub LastDripRequestAt get(fn last_drip_request_at):
+            double_map
+                hasher(twox_64_concat) T::AccountId,   // Faucet account
+                hasher(blake2_128_concat) T::AccountId // Recipient account
+            => Option<T::BlockNumber>;
+
+        /// Drip requests whose `LastDripRequestAt` records should be removed at a given block.
+        pub DripRequestsExpiringAt get(fn drip_requests_expiring_at):
+            map hasher(twox_64_concat) T::BlockNumber
+            => Vec<(/* faucet */ T::AccountId, /* recipient */ T::AccountId)>;
+
+        /// A queue of unsigned drip requests that are waiting to be processed.
+        pub PendingDripRequests get(fn pending_drip_requests):
+            Vec<(/* faucet */ T::AccountId, /* recipient */ T::AccountId)>;
     }
 }
 
@@ -91,6 +137,15 @@ decl_event!(
             AccountId, // Recipient account
             Balance    // Amount dripped
         ),
+        DripRequested(
+            AccountId, // Faucet account
+            AccountId  // Recipient account
+        ),
+        DripRequestFailed(
+            AccountId, // Faucet account
+            AccountId, // Recipient account
+            DispatchError
+        ),
     }
 );
 
@@ -114,6 +169,15 @@ decl_error! {
         
         PeriodLimitReached,
         DripLimitReached,
+
+        /// Proof of work of a drip request does not meet the required difficulty.
+        InvalidDripRequestProof,
+        /// This recipient already requested a drip from this faucet in the current period.
+        DripAlreadyRequested,
+        /// There are too many drip requests waiting in a queue.
+        TooManyPendingDripRequests,
+        /// Proof of work of a drip request is made on a future or too old block.
+        DripRequestProofIsOutdated,
     }
 }
 
@@ -122,9 +186,23 @@ decl_module! {
         // Initializing errors
         type Error = Error<T>;
 
+        /// The number of leading zero bits required in a proof of work of a drip request.
+        const DripRequestDifficulty: u32 = T::DripRequestDifficulty::get();
+
+        /// Max number of drip requests that can wait in a queue to be processed.
+        const MaxPendingDripRequests: u16 = T::MaxPendingDripRequests::get();
+
+        /// Max number of drip requests processed in `on_initialize` of a single block.
+        const MaxDripsPerBlock: u16 = T::MaxDripsPerBlock::get();
+
         // Initializing events
         fn deposit_event() = default;
 
+        fn on_initialize(block_number: T::BlockNumber) -> Weight {
+            Self::prune_expired_drip_requests(block_number)
+                .saturating_add(Self::process_pending_drip_requests())
+        }
+
         #[weight = 50_000 + T::DbWeight::get().reads_writes(2, 1)]
         pub fn add_faucet(
             origin,
@@ -257,54 +335,220 @@ decl_module! {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let faucet = ensure_signed(origin)?;
+            Self::do_drip(faucet, recipient, amount)
+        }
 
-            // Validate input values
-            ensure!(faucet != recipient, Error::<T>::RecipientEqualsFaucet);
-            ensure!(amount > Zero::zero(), Error::<T>::ZeroDripAmountProvided);
-
-            let mut settings = Self::require_faucet(&faucet)?;
-            ensure!(settings.enabled, Error::<T>::FaucetDisabled);
-            ensure!(amount <= settings.drip_limit, Error::<T>::DripLimitReached);
+        /// Request a drip of `drip_limit` tokens from a faucet to a recipient
+        /// without a signature of the faucet account.
+        ///
+        /// `nonce` is a proof of work: a hash of `(faucet, recipient, proof_block, proof_block_hash, nonce)`
+        /// should have at least `DripRequestDifficulty` leading zero bits.
+        /// `proof_block` should be one of the last `DRIP_REQUEST_PROOF_MAX_AGE` blocks,
+        /// so a proof cannot be computed in advance or reused in the next periods.
+        /// A recipient can request a drip from the same faucet once per faucet period.
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 3)]
+        pub fn request_drip(
+            origin,
+            faucet: T::AccountId,
+            recipient: T::AccountId,
+            proof_block: T::BlockNumber,
+            nonce: u64,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
 
-            let faucet_balance = T::Currency::free_balance(&faucet);
-            ensure!(amount <= faucet_balance, Error::<T>::NotEnoughFreeBalanceOnFaucet);
+            let settings = Self::ensure_valid_drip_request(&faucet, &recipient, proof_block, nonce)?;
 
             let current_block = <system::Module<T>>::block_number();
+            LastDripRequestAt::<T>::insert(&faucet, &recipient, current_block);
+            DripRequestsExpiringAt::<T>::mutate(
+                current_block.saturating_add(settings.period),
+                |requests| requests.push((faucet.clone(), recipient.clone()))
+            );
+            PendingDripRequests::<T>::mutate(|requests| requests.push((faucet.clone(), recipient.clone())));
 
-            if settings.next_period_at <= current_block {
-                // Move to the next period and reset the period stats
-                settings.next_period_at = current_block.saturating_add(settings.period);
-                settings.dripped_in_current_period = Zero::zero();
-            }
+            Self::deposit_event(RawEvent::DripRequested(faucet, recipient));
+            Ok(())
+        }
+    }
+}
 
-            // Calculate have many tokens still can be dripped in the current period
-            let tokens_left_in_current_period = settings.period_limit
-                .saturating_sub(settings.dripped_in_current_period);
+impl<T: Trait> Module<T> {
 
-            ensure!(amount <= tokens_left_in_current_period, Error::<T>::PeriodLimitReached);
+    pub fn require_faucet(faucet: &T::AccountId) -> Result<Faucet<T>, DispatchError> {
+        Ok(Self::faucet_by_account(faucet).ok_or(Error::<T>::FaucetNotFound)?)
+    }
 
-            T::Currency::transfer(
-                &faucet,
-                &recipient,
-                amount,
-                ExistenceRequirement::KeepAlive
-            )?;
+    fn do_drip(
+        faucet: T::AccountId,
+        recipient: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
 
-            settings.dripped_in_current_period = amount
-                .saturating_add(settings.dripped_in_current_period);
+        // Validate input values
+        ensure!(faucet != recipient, Error::<T>::RecipientEqualsFaucet);
+        ensure!(amount > Zero::zero(), Error::<T>::ZeroDripAmountProvided);
 
-            FaucetByAccount::<T>::insert(&faucet, settings);
+        let mut settings = Self::require_faucet(&faucet)?;
+        ensure!(settings.enabled, Error::<T>::FaucetDisabled);
+        ensure!(amount <= settings.drip_limit, Error::<T>::DripLimitReached);
 
-            Self::deposit_event(RawEvent::Dripped(faucet, recipient, amount));
-            Ok(())
+        let faucet_balance = T::Currency::free_balance(&faucet);
+        ensure!(amount <= faucet_balance, Error::<T>::NotEnoughFreeBalanceOnFaucet);
+
+        let current_block = <system::Module<T>>::block_number();
+
+        if settings.next_period_at <= current_block {
+            // Move to the next period and reset the period stats
+            settings.next_period_at = current_block.saturating_add(settings.period);
+            settings.dripped_in_current_period = Zero::zero();
+        }
+
+        // Calculate have many tokens still can be dripped in the current period
+        let tokens_left_in_current_period = settings.period_limit
+            .saturating_sub(settings.dripped_in_current_period);
+
+        ensure!(amount <= tokens_left_in_current_period, Error::<T>::PeriodLimitReached);
+
+        T::Currency::transfer(
+            &faucet,
+            &recipient,
+            amount,
+            ExistenceRequirement::KeepAlive
+        )?;
+
+        settings.dripped_in_current_period = amount
+            .saturating_add(settings.dripped_in_current_period);
+
+        FaucetByAccount::<T>::insert(&faucet, settings);
+
+        Self::deposit_event(RawEvent::Dripped(faucet, recipient, amount));
+        Ok(())
+    }
+
+    /// Check whether a hash of `(faucet, recipient, proof_block, proof_block_hash, nonce)`
+    /// has enough leading zero bits.
+    pub fn is_valid_drip_request_proof(
+        faucet: &T::AccountId,
+        recipient: &T::AccountId,
+        proof_block: T::BlockNumber,
+        nonce: u64,
+    ) -> bool {
+        let proof_block_hash = <system::Module<T>>::block_hash(proof_block);
+        let hash = T::Hashing::hash_of(&(faucet, recipient, proof_block, proof_block_hash, nonce));
+
+        let mut leading_zeros = 0u32;
+        for byte in hash.as_ref().iter() {
+            leading_zeros = leading_zeros.saturating_add(byte.leading_zeros());
+            if *byte != 0 { break; }
         }
+
+        leading_zeros >= T::DripRequestDifficulty::get()
     }
-}
 
-impl<T: Trait> Module<T> {
+    /// Check that a drip request can be queued: a proof of work is valid and made on a recent block,
+    /// a faucet is enabled and a recipient didn't request a drip in the current faucet period.
+    fn ensure_valid_drip_request(
+        faucet: &T::AccountId,
+        recipient: &T::AccountId,
+        proof_block: T::BlockNumber,
+        nonce: u64,
+    ) -> Result<Faucet<T>, DispatchError> {
+        ensure!(faucet != recipient, Error::<T>::RecipientEqualsFaucet);
+
+        let current_block = <system::Module<T>>::block_number();
+        ensure!(
+            proof_block < current_block &&
+                current_block.saturating_sub(proof_block) <= DRIP_REQUEST_PROOF_MAX_AGE.into(),
+            Error::<T>::DripRequestProofIsOutdated
+        );
+        ensure!(
+            Self::is_valid_drip_request_proof(faucet, recipient, proof_block, nonce),
+            Error::<T>::InvalidDripRequestProof
+        );
+
+        let settings = Self::require_faucet(faucet)?;
+        ensure!(settings.enabled, Error::<T>::FaucetDisabled);
+
+        if let Some(last_request_at) = Self::last_drip_request_at(faucet, recipient) {
+            ensure!(
+                last_request_at.saturating_add(settings.period) <= current_block,
+                Error::<T>::DripAlreadyRequested
+            );
+        }
 
-    pub fn require_faucet(faucet: &T::AccountId) -> Result<Faucet<T>, DispatchError> {
-        Ok(Self::faucet_by_account(faucet).ok_or(Error::<T>::FaucetNotFound)?)
+        ensure!(
+            Self::pending_drip_requests().len() < T::MaxPendingDripRequests::get() as usize,
+            Error::<T>::TooManyPendingDripRequests
+        );
+
+        Ok(settings)
+    }
+
+    /// Remove `LastDripRequestAt` records of drip requests whose faucet period has passed.
+    /// At most `MaxPendingDripRequests` records are checked per block, the rest are moved to the next block.
+    fn prune_expired_drip_requests(block_number: T::BlockNumber) -> Weight {
+        let mut requests = DripRequestsExpiringAt::<T>::take(block_number);
+        if requests.is_empty() {
+            return T::DbWeight::get().reads_writes(1, 1);
+        }
+
+        let max_requests = T::MaxPendingDripRequests::get() as usize;
+        if requests.len() > max_requests {
+            let left_requests = requests.split_off(max_requests);
+            DripRequestsExpiringAt::<T>::mutate(
+                block_number.saturating_add(One::one()),
+                |next_requests| next_requests.extend(left_requests)
+            );
+        }
+
+        let to_prune = requests.len() as Weight;
+        for (faucet, recipient) in requests.into_iter() {
+            let last_request_at = match Self::last_drip_request_at(&faucet, &recipient) {
+                Some(last_request_at) => last_request_at,
+                None => continue,
+            };
+
+            let expires_at = Self::faucet_by_account(&faucet)
+                .map(|settings| last_request_at.saturating_add(settings.period))
+                .unwrap_or(block_number);
+
+            if expires_at > block_number {
+                // The faucet period was extended after this request, so check it again later.
+                DripRequestsExpiringAt::<T>::mutate(expires_at, |requests| requests.push((faucet, recipient)));
+            } else {
+                LastDripRequestAt::<T>::remove(&faucet, &recipient);
+            }
+        }
+
+        T::DbWeight::get().reads_writes(2 + 2 * to_prune, 2 + to_prune)
+    }
+
+    /// Drip tokens for up to `MaxDripsPerBlock` requests from the front of the queue.
+    fn process_pending_drip_requests() -> Weight {
+        let mut requests = Self::pending_drip_requests();
+        if requests.is_empty() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let to_process = requests.len().min(T::MaxDripsPerBlock::get() as usize);
+        let left_requests = requests.split_off(to_process);
+
+        for (faucet, recipient) in requests.into_iter() {
+            let drip_limit = Self::faucet_by_account(&faucet)
+                .map(|settings| settings.drip_limit)
+                .unwrap_or_else(Zero::zero);
+
+            if let Err(error) = Self::do_drip(faucet.clone(), recipient.clone(), drip_limit) {
+                Self::deposit_event(RawEvent::DripRequestFailed(faucet, recipient, error));
+            }
+        }
+
+        PendingDripRequests::<T>::put(left_requests);
+
+        50_000 * to_process as Weight + T::DbWeight::get().reads_writes(
+            1 + 3 * to_process as Weight,
+            1 + 3 * to_process as Weight
+        )
     }
 
     fn ensure_period_not_zero(period: T::BlockNumber) -> DispatchResult {
@@ -323,6 +567,30 @@ impl<T: Trait> Module<T> {
     }
 }
 
+impl<T: Trait> frame_support::unsigned::ValidateUnsigned for Module<T> {
+    type Call = Call<T>;
+
+    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+        if let Call::request_drip(faucet, recipient, proof_block, nonce) = call {
+            if let Err(err) = Self::ensure_valid_drip_request(faucet, recipient, *proof_block, *nonce) {
+                return match err {
+                    DispatchError::Module { error, .. } => InvalidTransaction::Custom(error).into(),
+                    _ => InvalidTransaction::Call.into(),
+                };
+            }
+
+            ValidTransaction::with_tag_prefix("FaucetsDripRequest")
+                .priority(T::DripRequestUnsignedPriority::get())
+                .and_provides((faucet, recipient))
+                .longevity(DRIP_REQUEST_LONGEVITY)
+                .propagate(true)
+                .build()
+        } else {
+            InvalidTransaction::Call.into()
+        }
+    }
+}
+
 impl<T: Trait> Faucet<T> {
 
     pub fn new(
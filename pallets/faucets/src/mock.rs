@@ -4,7 +4,8 @@ use crate::{Module, Trait, Faucet, FaucetUpdate};
 use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, testing::Header, RuntimeDebug, Perbill, Storage
+    traits::{BlakeTwo256, IdentityLookup}, testing::Header, RuntimeDebug, Perbill, Storage,
+    transaction_validity::TransactionPriority,
 };
 
 use frame_support::{
@@ -92,6 +93,8 @@ impl pallet_balances::Trait for Test {
 parameter_types! {
     pub const MinHandleLen: u32 = 5;
     pub const MaxHandleLen: u32 = 50;
+    pub const MaxRawContentLen: u32 = 64;
+    pub const RawContentDepositPerByte: u64 = 0;
 }
 
 impl pallet_utils::Trait for Test {
@@ -99,11 +102,25 @@ impl pallet_utils::Trait for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type MaxRawContentLen = MaxRawContentLen;
+    type RawContentDepositPerByte = RawContentDepositPerByte;
+    type ContentValidator = pallet_utils::DefaultContentValidator<Test>;
+}
+
+parameter_types! {
+    pub const DripRequestDifficulty: u32 = 4;
+    pub const MaxPendingDripRequests: u16 = 3;
+    pub const MaxDripsPerBlock: u16 = 2;
+    pub const DripRequestUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
 }
 
 impl Trait for Test {
     type Event = ();
     type Currency = Balances;
+    type DripRequestDifficulty = DripRequestDifficulty;
+    type MaxPendingDripRequests = MaxPendingDripRequests;
+    type MaxDripsPerBlock = MaxDripsPerBlock;
+    type DripRequestUnsignedPriority = DripRequestUnsignedPriority;
 }
 
 pub(crate) type System = system::Module<Test>;
@@ -280,3 +297,41 @@ pub(crate) fn _drip(
         amount.unwrap_or(default_faucet().drip_limit)
     )
 }
+
+/// A block on which a proof of work of a drip request is made by default: the previous one.
+pub(crate) fn default_proof_block() -> BlockNumber {
+    System::block_number().saturating_sub(1)
+}
+
+/// Find the first nonce that satisfies a proof of work of a drip request.
+pub(crate) fn find_drip_request_nonce(faucet: AccountId, recipient: AccountId, proof_block: BlockNumber) -> u64 {
+    (0..).find(|nonce| Faucets::is_valid_drip_request_proof(&faucet, &recipient, proof_block, *nonce)).unwrap()
+}
+
+/// Find the first nonce that does not satisfy a proof of work of a drip request.
+pub(crate) fn find_invalid_drip_request_nonce(faucet: AccountId, recipient: AccountId, proof_block: BlockNumber) -> u64 {
+    (0..).find(|nonce| !Faucets::is_valid_drip_request_proof(&faucet, &recipient, proof_block, *nonce)).unwrap()
+}
+
+pub(crate) fn _request_default_drip() -> DispatchResult {
+    _request_drip(None, None, None, None, None)
+}
+
+pub(crate) fn _request_drip(
+    origin: Option<Origin>,
+    faucet: Option<AccountId>,
+    recipient: Option<AccountId>,
+    proof_block: Option<BlockNumber>,
+    nonce: Option<u64>,
+) -> DispatchResult {
+    let faucet = faucet.unwrap_or(FAUCET1);
+    let recipient = recipient.unwrap_or(ACCOUNT1);
+    let proof_block = proof_block.unwrap_or_else(default_proof_block);
+    Faucets::request_drip(
+        origin.unwrap_or_else(Origin::none),
+        faucet,
+        recipient,
+        proof_block,
+        nonce.unwrap_or_else(|| find_drip_request_nonce(faucet, recipient, proof_block))
+    )
+}
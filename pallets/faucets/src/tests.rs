@@ -1,5 +1,5 @@
 use crate::{Error, mock::*, Faucet, FaucetUpdate};
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{assert_ok, assert_noop, traits::OnInitialize};
 use sp_runtime::DispatchError::BadOrigin;
 
 // Add faucet
@@ -423,3 +423,193 @@ fn drip_should_fail_when_faucet_is_disabled_and_work_again_after_faucet_enabled(
         assert_eq!(Balances::free_balance(ACCOUNT1), default_faucet().drip_limit);
     });
 }
+
+// Request drip
+// ----------------------------------------------------------------------------
+
+#[test]
+fn request_drip_should_work_and_drip_on_next_block() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_request_default_drip());
+
+        assert_eq!(Faucets::pending_drip_requests(), vec![(FAUCET1, ACCOUNT1)]);
+        assert_eq!(Faucets::last_drip_request_at(FAUCET1, ACCOUNT1), Some(1));
+
+        // Tokens should not be dripped until the queue is processed
+        assert_eq!(Balances::free_balance(ACCOUNT1), 0);
+
+        Faucets::on_initialize(2);
+
+        assert!(Faucets::pending_drip_requests().is_empty());
+        assert_eq!(Balances::free_balance(ACCOUNT1), default_faucet().drip_limit);
+    });
+}
+
+#[test]
+fn request_drip_should_work_for_same_recipient_in_next_period() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_request_default_drip());
+        Faucets::on_initialize(2);
+
+        System::set_block_number(1 + default_faucet().period);
+        assert_ok!(_request_default_drip());
+        Faucets::on_initialize(2 + default_faucet().period);
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), default_faucet().drip_limit * 2);
+    });
+}
+
+#[test]
+fn on_initialize_should_process_limited_number_of_drip_requests() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        let recipients = vec![ACCOUNT1, ACCOUNT1 + 1, ACCOUNT1 + 2];
+        for recipient in recipients.iter() {
+            assert_ok!(_request_drip(None, None, Some(*recipient), None, None));
+        }
+
+        // Only MaxDripsPerBlock requests should be processed in one block
+        Faucets::on_initialize(2);
+        assert_eq!(Faucets::pending_drip_requests(), vec![(FAUCET1, ACCOUNT1 + 2)]);
+        assert_eq!(Balances::free_balance(ACCOUNT1 + 1), default_faucet().drip_limit);
+        assert_eq!(Balances::free_balance(ACCOUNT1 + 2), 0);
+
+        // The third request should fail as the period limit is reached
+        Faucets::on_initialize(3);
+        assert!(Faucets::pending_drip_requests().is_empty());
+        assert_eq!(Balances::free_balance(ACCOUNT1 + 2), 0);
+    });
+}
+
+#[test]
+fn on_initialize_should_remove_last_drip_request_after_faucet_period() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_request_default_drip());
+
+        let expires_at = 1 + default_faucet().period;
+        assert_eq!(Faucets::drip_requests_expiring_at(expires_at), vec![(FAUCET1, ACCOUNT1)]);
+
+        Faucets::on_initialize(expires_at - 1);
+        assert_eq!(Faucets::last_drip_request_at(FAUCET1, ACCOUNT1), Some(1));
+
+        Faucets::on_initialize(expires_at);
+        assert_eq!(Faucets::last_drip_request_at(FAUCET1, ACCOUNT1), None);
+        assert!(Faucets::drip_requests_expiring_at(expires_at).is_empty());
+    });
+}
+
+#[test]
+fn on_initialize_should_keep_last_drip_request_when_faucet_period_extended() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_request_default_drip());
+
+        let period = default_faucet().period;
+        assert_ok!(_update_faucet_settings(
+            FaucetUpdate {
+                enabled: None,
+                period: Some(period * 2),
+                period_limit: None,
+                drip_limit: None
+            }
+        ));
+
+        Faucets::on_initialize(1 + period);
+        assert_eq!(Faucets::last_drip_request_at(FAUCET1, ACCOUNT1), Some(1));
+        assert_eq!(Faucets::drip_requests_expiring_at(1 + period * 2), vec![(FAUCET1, ACCOUNT1)]);
+
+        Faucets::on_initialize(1 + period * 2);
+        assert_eq!(Faucets::last_drip_request_at(FAUCET1, ACCOUNT1), None);
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_origin_is_signed() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_noop!(
+            _request_drip(Some(Origin::signed(ACCOUNT1)), None, None, None, None),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_proof_is_invalid() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        let nonce = find_invalid_drip_request_nonce(FAUCET1, ACCOUNT1, default_proof_block());
+        assert_noop!(
+            _request_drip(None, None, None, None, Some(nonce)),
+            Error::<Test>::InvalidDripRequestProof
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_proof_is_made_on_current_or_old_block() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_noop!(
+            _request_drip(None, None, None, Some(1), None),
+            Error::<Test>::DripRequestProofIsOutdated
+        );
+
+        System::set_block_number(100);
+        assert_noop!(
+            _request_drip(None, None, None, Some(1), None),
+            Error::<Test>::DripRequestProofIsOutdated
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_already_requested_in_current_period() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_request_default_drip());
+
+        System::set_block_number(default_faucet().period);
+        assert_noop!(
+            _request_default_drip(),
+            Error::<Test>::DripAlreadyRequested
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_faucet_is_disabled() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_ok!(_update_faucet_settings(
+            FaucetUpdate {
+                enabled: Some(false),
+                period: None,
+                period_limit: None,
+                drip_limit: None
+            }
+        ));
+
+        assert_noop!(
+            _request_default_drip(),
+            Error::<Test>::FaucetDisabled
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_recipient_equals_faucet() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        assert_noop!(
+            _request_drip(None, None, Some(FAUCET1), None, None),
+            Error::<Test>::RecipientEqualsFaucet
+        );
+    });
+}
+
+#[test]
+fn request_drip_should_fail_when_too_many_pending_requests() {
+    ExtBuilder::build_with_faucet().execute_with(|| {
+        for recipient in ACCOUNT1..ACCOUNT1 + 3 {
+            assert_ok!(_request_drip(None, None, Some(recipient), None, None));
+        }
+
+        assert_noop!(
+            _request_drip(None, None, Some(ACCOUNT1 + 3), None, None),
+            Error::<Test>::TooManyPendingDripRequests
+        );
+    });
+}
@@ -203,6 +203,9 @@ decl_error! {
         SuggestedStatusInWrongScope,
         /// Entity status has already been suggested by this moderator account.
         AlreadySuggestedEntityStatus,
+        /// `Raw` content is not supported for a report reason, as reports are never deleted
+        /// to release its deposit.
+        RawReasonIsNotSupported,
     }
 }
 
@@ -232,7 +235,8 @@ decl_module! {
 
             // TODO check this func, if looks strange
             Utils::<T>::ensure_content_is_some(&reason).map_err(|_| Error::<T>::ReasonIsEmpty)?;
-            
+            ensure!(!reason.is_raw(), Error::<T>::RawReasonIsNotSupported);
+
             Utils::<T>::is_valid_content(reason.clone())?;
 
             ensure!(Spaces::<T>::require_space(scope).is_ok(), Error::<T>::ScopeNotFound);
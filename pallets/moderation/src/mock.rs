@@ -1,7 +1,7 @@
 use crate::{Module, Trait, EntityId, EntityStatus, ReportId, SpaceModerationSettingsUpdate};
 use sp_core::H256;
 use frame_support::{
-    impl_outer_origin, parameter_types, assert_ok, StorageMap,
+    impl_outer_origin, impl_outer_dispatch, parameter_types, assert_ok, StorageMap,
     weights::Weight,
     dispatch::{DispatchResult},
 };
@@ -22,6 +22,12 @@ impl_outer_origin! {
     pub enum Origin for Test {}
 }
 
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        frame_system::System,
+    }
+}
+
 #[derive(Clone, Eq, PartialEq)]
 pub struct Test;
 
@@ -35,7 +41,7 @@ parameter_types! {
 impl system::Trait for Test {
     type BaseCallFilter = ();
     type Origin = Origin;
-    type Call = ();
+    type Call = Call;
     type Index = u64;
     type BlockNumber = u64;
     type Hash = H256;
@@ -74,6 +80,8 @@ impl pallet_timestamp::Trait for Test {
 parameter_types! {
     pub const MinHandleLen: u32 = 5;
     pub const MaxHandleLen: u32 = 50;
+    pub const MaxRawContentLen: u32 = 64;
+    pub const RawContentDepositPerByte: u64 = 0;
 }
 
 impl pallet_utils::Trait for Test {
@@ -81,6 +89,9 @@ impl pallet_utils::Trait for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type MaxRawContentLen = MaxRawContentLen;
+    type RawContentDepositPerByte = RawContentDepositPerByte;
+    type ContentValidator = pallet_utils::DefaultContentValidator<Test>;
 }
 
 parameter_types! {
@@ -108,11 +119,16 @@ impl pallet_spaces::Trait for Test {
     type Currency = Balances;
     type Roles = Roles;
     type SpaceFollows = SpaceFollows;
+    type SpaceCoOwners = ();
     type BeforeSpaceCreated = SpaceFollows;
     type AfterSpaceUpdated = ();
     type IsAccountBlocked = Moderation;
     type IsContentBlocked = Moderation;
     type HandleDeposit = ();
+    type Call = Call;
+    type SpaceActionsFilter = ();
+    type CallSpaceResolver = ();
+    type CallRawContentDetector = ();
 }
 
 impl pallet_space_follows::Trait for Test {
@@ -121,29 +137,51 @@ impl pallet_space_follows::Trait for Test {
     type BeforeSpaceUnfollowed = ();
 }
 
+impl pallet_profile_follows::Trait for Test {
+    type Event = ();
+    type BeforeAccountFollowed = ();
+    type BeforeAccountUnfollowed = ();
+}
+
 parameter_types! {
     pub const MaxCommentDepth: u32 = 10;
+    pub const MaxTagsPerPost: u16 = 3;
+    pub const MaxTagLen: u32 = 10;
+    pub const MaxMentionsPerPost: u16 = 3;
+    pub const HotPointsDoublingBlocks: u64 = 7_200;
+    pub const HotPostsWindowBlocks: u64 = 100_800;
 }
 
 impl pallet_posts::Trait for Test {
     type Event = ();
     type MaxCommentDepth = MaxCommentDepth;
     type PostScores = ();
+    type AfterPostCreated = ();
     type AfterPostUpdated = ();
     type IsPostBlocked = Moderation;
+    type MaxTagsPerPost = MaxTagsPerPost;
+    type MaxTagLen = MaxTagLen;
+    type MaxMentionsPerPost = MaxMentionsPerPost;
+    type HotPointsDoublingBlocks = HotPointsDoublingBlocks;
+    type HotPostsWindowBlocks = HotPostsWindowBlocks;
 }
 
 parameter_types! {
     pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+    pub const MaxExpiredRolesToDeletePerBlock: u16 = 20;
+    pub const MaxRolesToClone: u16 = 20;
 }
 
 impl pallet_roles::Trait for Test {
     type Event = ();
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type MaxExpiredRolesToDeletePerBlock = MaxExpiredRolesToDeletePerBlock;
+    type MaxRolesToClone = MaxRolesToClone;
     type Spaces = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = Moderation;
     type IsContentBlocked = Moderation;
+    type AfterRoleGranted = ();
 }
 
 impl pallet_profiles::Trait for Test {
@@ -272,6 +310,8 @@ pub(crate) fn create_space_and_post() {
         Some(SPACE1),
         PostExtension::RegularPost,
         valid_content_ipfs(),
+        vec![],
+        vec![],
     ));
 }
 
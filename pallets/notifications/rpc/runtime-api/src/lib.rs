@@ -0,0 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_notifications::rpc::FlatNotification;
+
+sp_api::decl_runtime_apis! {
+    pub trait NotificationsApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_notifications(
+            account: AccountId,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatNotification<AccountId, BlockNumber>>;
+
+        fn get_unread_notifications_count(account: AccountId) -> u64;
+    }
+}
@@ -0,0 +1,233 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    dispatch::DispatchResult, traits::Get,
+};
+use sp_runtime::RuntimeDebug;
+use frame_system::{self as system, ensure_signed};
+
+use pallet_posts::{Module as Posts, Post, PostExtension, AfterPostCreated};
+use pallet_profile_follows::BeforeAccountFollowed;
+use pallet_reactions::{PostReactionIdByAccount, PostReactionScores, ReactionKind};
+use pallet_roles::{AfterRoleGranted, Role, RoleId};
+use pallet_utils::{PostId, User};
+
+pub mod rpc;
+
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum NotificationKind {
+    AccountFollowed,
+    PostReplied,
+    PostShared,
+    PostUpvoted,
+    PostDownvoted,
+    RoleGranted,
+}
+
+/// An entity that a notification is about.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum NotificationTarget<AccountId> {
+    Account(AccountId),
+    Post(PostId),
+    Role(RoleId),
+}
+
+/// Information about what happened (`kind`), who did it (`actor`), to what (`target`) and when.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Notification<T: Trait> {
+    pub kind: NotificationKind,
+    pub actor: T::AccountId,
+    pub target: NotificationTarget<T::AccountId>,
+    pub block: T::BlockNumber,
+}
+
+/// The pallet's configuration trait.
+pub trait Trait: system::Trait
+    + pallet_utils::Trait
+    + pallet_posts::Trait
+    + pallet_reactions::Trait
+    + pallet_roles::Trait
+{
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Max number of notifications stored per account.
+    /// The oldest notifications are overwritten when this limit is reached.
+    type MaxNotificationsPerAccount: Get<u16>;
+}
+
+// This pallet's storage items.
+decl_storage! {
+    trait Store for Module<T: Trait> as NotificationsModule {
+
+        /// A ring buffer of notifications of an account.
+        /// A notification with a sequential number `n` is stored in a slot `n % MaxNotificationsPerAccount`.
+        pub NotificationByAccountAndSlot get(fn notification_by_account_and_slot):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) u16
+            => Option<Notification<T>>;
+
+        /// The number of notifications ever received by an account.
+        /// It is also a sequential number of the next notification.
+        pub NotificationsCountByAccount get(fn notifications_count_by_account):
+            map hasher(blake2_128_concat) T::AccountId => u64;
+
+        /// The number of notifications read by an account.
+        /// Notifications with sequential numbers lower than this one are read.
+        pub ReadNotificationsCountByAccount get(fn read_notifications_count_by_account):
+            map hasher(blake2_128_concat) T::AccountId => u64;
+    }
+}
+
+decl_event!(
+    pub enum Event<T> where
+        <T as system::Trait>::AccountId,
+    {
+        NotificationsRead(AccountId, u64),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// Cannot mark as read notifications that were not received yet.
+        NotificationNotFound,
+        /// Notifications are already marked as read up to this one.
+        NotificationsAlreadyRead,
+    }
+}
+
+decl_module! {
+  pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+
+    /// Max number of notifications stored per account.
+    const MaxNotificationsPerAccount: u16 = T::MaxNotificationsPerAccount::get();
+
+    // Initializing errors
+    type Error = Error<T>;
+
+    // Initializing events
+    fn deposit_event() = default;
+
+    /// Mark notifications of the caller with sequential numbers lower than `read_count` as read.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+    pub fn mark_notifications_as_read(origin, read_count: u64) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      ensure!(read_count <= Self::notifications_count_by_account(&who), Error::<T>::NotificationNotFound);
+      ensure!(read_count > Self::read_notifications_count_by_account(&who), Error::<T>::NotificationsAlreadyRead);
+
+      ReadNotificationsCountByAccount::<T>::insert(who.clone(), read_count);
+
+      Self::deposit_event(RawEvent::NotificationsRead(who, read_count));
+      Ok(())
+    }
+  }
+}
+
+impl<T: Trait> Module<T> {
+
+    /// Put a notification into an inbox of `account` overwriting the oldest one if the inbox is full.
+    /// Accounts are not notified about their own actions.
+    pub fn notify(
+        account: T::AccountId,
+        kind: NotificationKind,
+        actor: T::AccountId,
+        target: NotificationTarget<T::AccountId>,
+    ) {
+        let max_notifications = T::MaxNotificationsPerAccount::get();
+        if account == actor || max_notifications == 0 {
+            return;
+        }
+
+        let count = Self::notifications_count_by_account(&account);
+        let slot = (count % max_notifications as u64) as u16;
+
+        NotificationByAccountAndSlot::<T>::insert(&account, slot, Notification {
+            kind,
+            actor,
+            target,
+            block: <system::Module<T>>::block_number(),
+        });
+        NotificationsCountByAccount::<T>::insert(&account, count.saturating_add(1));
+    }
+
+    /// Get a notification of `account` by its sequential number
+    /// or `None` if it was overwritten or not received yet.
+    pub fn notification_by_account(account: &T::AccountId, index: u64) -> Option<Notification<T>> {
+        let max_notifications = T::MaxNotificationsPerAccount::get() as u64;
+        let count = Self::notifications_count_by_account(account);
+
+        if index >= count || index < count.saturating_sub(max_notifications) {
+            return None;
+        }
+
+        Self::notification_by_account_and_slot(account, (index % max_notifications) as u16)
+    }
+}
+
+impl<T: Trait> BeforeAccountFollowed<T> for Module<T> {
+    fn before_account_followed(follower: T::AccountId, _follower_reputation: u32, following: T::AccountId) -> DispatchResult {
+        Self::notify(
+            following.clone(),
+            NotificationKind::AccountFollowed,
+            follower,
+            NotificationTarget::Account(following),
+        );
+        Ok(())
+    }
+}
+
+impl<T: Trait> AfterPostCreated<T> for Module<T> {
+    fn after_post_created(post: &Post<T>) {
+        let (kind, notified_post_id) = match post.extension {
+            PostExtension::Comment(comment_ext) =>
+                (NotificationKind::PostReplied, comment_ext.parent_id.unwrap_or(comment_ext.root_post_id)),
+            PostExtension::SharedPost(original_post_id) =>
+                (NotificationKind::PostShared, original_post_id),
+            PostExtension::RegularPost => return,
+        };
+
+        if let Some(notified_post) = Posts::<T>::post_by_id(notified_post_id) {
+            Self::notify(notified_post.owner, kind, post.owner.clone(), NotificationTarget::Post(post.id));
+        }
+    }
+}
+
+impl<T: Trait> PostReactionScores<T> for Module<T> {
+    fn score_post_on_reaction(actor: T::AccountId, post: &mut Post<T>, reaction_kind: ReactionKind) -> DispatchResult {
+        // This handler is also called when a reaction is updated or deleted,
+        // but only new reactions should be notified about.
+        if PostReactionIdByAccount::<T>::contains_key((actor.clone(), post.id)) {
+            return Ok(());
+        }
+
+        let kind = match reaction_kind {
+            ReactionKind::Upvote => NotificationKind::PostUpvoted,
+            ReactionKind::Downvote => NotificationKind::PostDownvoted,
+        };
+
+        Self::notify(post.owner.clone(), kind, actor, NotificationTarget::Post(post.id));
+        Ok(())
+    }
+}
+
+impl<T: Trait> AfterRoleGranted<T> for Module<T> {
+    fn after_role_granted(granted_by: &T::AccountId, role: &Role<T>, users: &[User<T::AccountId>]) {
+        for user in users {
+            if let User::Account(account) = user {
+                Self::notify(
+                    account.clone(),
+                    NotificationKind::RoleGranted,
+                    granted_by.clone(),
+                    NotificationTarget::Role(role.id),
+                );
+            }
+        }
+    }
+}
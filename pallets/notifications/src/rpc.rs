@@ -0,0 +1,65 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use crate::{Module, Notification, NotificationKind, NotificationTarget, Trait};
+
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatNotification<AccountId, BlockNumber> {
+    /// A sequential number of a notification that should be used to mark notifications as read.
+    pub index: u64,
+    pub kind: NotificationKind,
+    pub actor: AccountId,
+    pub target: NotificationTarget<AccountId>,
+    pub block: BlockNumber,
+    pub is_read: bool,
+}
+
+impl<T: Trait> Module<T> {
+    fn flatten_notification(
+        index: u64,
+        notification: Notification<T>,
+        read_count: u64,
+    ) -> FlatNotification<T::AccountId, T::BlockNumber> {
+        let Notification { kind, actor, target, block } = notification;
+
+        FlatNotification {
+            index,
+            kind,
+            actor,
+            target,
+            block,
+            is_read: index < read_count,
+        }
+    }
+
+    /// Get a page of notifications of `account` starting from the latest one.
+    pub fn get_notifications(
+        account: T::AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatNotification<T::AccountId, T::BlockNumber>> {
+        let read_count = Self::read_notifications_count_by_account(&account);
+        let end = Self::notifications_count_by_account(&account).saturating_sub(offset);
+        let start = end.saturating_sub(limit as u64);
+
+        (start..end).rev()
+            .filter_map(|index|
+                Self::notification_by_account(&account, index)
+                    .map(|notification| Self::flatten_notification(index, notification, read_count))
+            )
+            .collect()
+    }
+
+    /// Get the number of unread notifications of `account` that are still stored.
+    pub fn get_unread_notifications_count(account: T::AccountId) -> u64 {
+        let count = Self::notifications_count_by_account(&account);
+        let oldest_stored = count.saturating_sub(T::MaxNotificationsPerAccount::get() as u64);
+        let read_count = Self::read_notifications_count_by_account(&account).max(oldest_stored);
+
+        count.saturating_sub(read_count)
+    }
+}
@@ -145,6 +145,21 @@ decl_module! {
 }
 
 impl SpacePermission {
+  /// Whether this permission is not granted to co-owners of a multi-owned space as `space_owner`:
+  /// such actions should be approved by other co-owners via call proposals.
+  pub fn is_owner_only(&self) -> bool {
+    matches!(
+      self,
+      SpacePermission::ManageRoles |
+      SpacePermission::RepresentSpaceInternally |
+      SpacePermission::RepresentSpaceExternally |
+      SpacePermission::UpdateSpace |
+      SpacePermission::OverrideSubspacePermissions |
+      SpacePermission::OverridePostPermissions |
+      SpacePermission::UpdateSpaceSettings
+    )
+  }
+
   fn is_present_in_role(&self, perms_opt: Option<SpacePermissionSet>) -> bool {
     if let Some(perms) = perms_opt {
       if perms.contains(self) {
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_post_history::rpc::FlatPostHistoryRecord;
+use pallet_utils::{PostId, rpc::map_rpc_error};
+pub use post_history_runtime_api::PostHistoryApi as PostHistoryRuntimeApi;
+
+#[rpc]
+pub trait PostHistoryApi<BlockHash, AccountId, BlockNumber> {
+    #[rpc(name = "postHistory_getPostEditHistory")]
+    fn get_post_edit_history(
+        &self,
+        at: Option<BlockHash>,
+        post_id: PostId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPostHistoryRecord<AccountId, BlockNumber>>>;
+}
+
+pub struct PostHistory<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> PostHistory<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber> PostHistoryApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for PostHistory<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: PostHistoryRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    fn get_post_edit_history(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        post_id: PostId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPostHistoryRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_post_edit_history(&at, post_id, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
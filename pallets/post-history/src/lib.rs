@@ -1,40 +1,83 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{decl_module, decl_storage};
+use frame_support::{
+    decl_module, decl_storage,
+    storage::IterableStorageMap,
+    traits::Get,
+    weights::Weight,
+};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
 use frame_system::{self as system};
 
 use pallet_posts::{Post, PostUpdate, AfterPostUpdated};
-use pallet_utils::{WhoAndWhen, PostId};
+use pallet_utils::{WhoAndWhen, Content, PostId, SpaceId};
+
+pub mod rpc;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
 pub struct PostHistoryRecord<T: Trait> {
     pub edited: WhoAndWhen<T>,
-    pub old_data: PostUpdate,
+    pub old_data: PostUpdate<T::AccountId>,
 }
 
+/// A layout of `PostUpdate` before tags and mentions were added to posts.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct OldPostUpdate {
+    pub space_id: Option<SpaceId>,
+    pub content: Option<Content>,
+    pub hidden: Option<bool>,
+}
+
+/// A history record stored in the deprecated `EditHistory` map.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct OldPostHistoryRecord<T: Trait> {
+    pub edited: WhoAndWhen<T>,
+    pub old_data: OldPostUpdate,
+}
+
+/// Max number of deprecated post histories moved to `PostEditHistory` in a single block.
+pub const MAX_EDIT_HISTORIES_TO_MIGRATE_PER_BLOCK: usize = 100;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
     + pallet_posts::Trait
-{}
+{
+    /// Max number of edit history records stored per entity.
+    /// The oldest records are removed when this limit is reached.
+    type MaxHistoryRecords: Get<u16>;
+}
 
 // This pallet's storage items.
 decl_storage! {
     trait Store for Module<T: Trait> as PostHistoryModule {
-        pub EditHistory get(fn edit_history):
+        /// Deprecated: records with the old layout of `PostUpdate`.
+        /// Replaced with `PostEditHistory` and drained in `on_initialize`.
+        pub EditHistory: map hasher(twox_64_concat) PostId => Vec<OldPostHistoryRecord<T>>;
+
+        pub PostEditHistory get(fn edit_history):
             map hasher(twox_64_concat) PostId => Vec<PostHistoryRecord<T>>;
+
+        /// Whether the deprecated `EditHistory` records were fully moved to `PostEditHistory`.
+        pub EditHistoryMigrated get(fn edit_history_migrated): bool;
     }
 }
 
 decl_module! {
-  pub struct Module<T: Trait> for enum Call where origin: T::Origin {}
+  pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+    /// Max number of edit history records stored per entity.
+    const MaxHistoryRecords: u16 = T::MaxHistoryRecords::get();
+
+    fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+      Self::migrate_edit_history(MAX_EDIT_HISTORIES_TO_MIGRATE_PER_BLOCK)
+    }
+  }
 }
 
 impl<T: Trait> PostHistoryRecord<T> {
-    fn new(updated_by: T::AccountId, old_data: PostUpdate) -> Self {
+    fn new(updated_by: T::AccountId, old_data: PostUpdate<T::AccountId>) -> Self {
         PostHistoryRecord {
             edited: WhoAndWhen::<T>::new(updated_by),
             old_data
@@ -42,9 +85,71 @@ impl<T: Trait> PostHistoryRecord<T> {
     }
 }
 
+impl<T: Trait> From<OldPostHistoryRecord<T>> for PostHistoryRecord<T> {
+    fn from(from: OldPostHistoryRecord<T>) -> Self {
+        let OldPostHistoryRecord { edited, old_data } = from;
+
+        PostHistoryRecord {
+            edited,
+            old_data: PostUpdate {
+                space_id: old_data.space_id,
+                content: old_data.content,
+                hidden: old_data.hidden,
+                tags: None,
+                mentions: None,
+            }
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Move up to `max_histories` post histories from the deprecated `EditHistory` map
+    /// to `PostEditHistory`.
+    ///
+    /// Old histories are removed while migrating. `EditHistoryMigrated` is set once the old map is empty.
+    pub fn migrate_edit_history(max_histories: usize) -> Weight {
+        if Self::edit_history_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut histories: usize = 0;
+
+        for (post_id, old_records) in EditHistory::<T>::drain().take(max_histories) {
+            histories += 1;
+
+            <PostEditHistory<T>>::mutate(post_id, |records| {
+                // Old records were made before any record that was saved after the upgrade.
+                let mut migrated: Vec<PostHistoryRecord<T>> = old_records.into_iter().map(Into::into).collect();
+                migrated.append(records);
+                *records = migrated;
+                Self::remove_oldest_records(records);
+            });
+        }
+
+        if histories < max_histories {
+            EditHistoryMigrated::put(true);
+        }
+
+        T::DbWeight::get().reads_writes(
+            2 + histories as Weight * 2,
+            1 + histories as Weight * 2
+        )
+    }
+
+    fn remove_oldest_records(records: &mut Vec<PostHistoryRecord<T>>) {
+        let max_records = T::MaxHistoryRecords::get() as usize;
+        if records.len() > max_records {
+            let records_to_remove = records.len() - max_records;
+            records.drain(..records_to_remove);
+        }
+    }
+}
+
 impl<T: Trait> AfterPostUpdated<T> for Module<T> {
-    fn after_post_updated(sender: T::AccountId, post: &Post<T>, old_data: PostUpdate) {
-        <EditHistory<T>>::mutate(post.id, |ids|
-            ids.push(PostHistoryRecord::<T>::new(sender, old_data)));
+    fn after_post_updated(sender: T::AccountId, post: &Post<T>, old_data: PostUpdate<T::AccountId>) {
+        <PostEditHistory<T>>::mutate(post.id, |records| {
+            records.push(PostHistoryRecord::<T>::new(sender, old_data));
+            Self::remove_oldest_records(records);
+        });
     }
 }
@@ -0,0 +1,54 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::SaturatedConversion;
+use sp_std::prelude::*;
+
+use pallet_utils::{Content, PostId, rpc::ShouldSkip};
+
+use crate::{Module, PostHistoryRecord, Trait};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPostHistoryRecord<AccountId, BlockNumber> {
+    pub edited_by: AccountId,
+    pub edited_at_block: BlockNumber,
+    pub edited_at_time: u64,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_content: Option<Content>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_hidden: Option<bool>,
+}
+
+impl<T: Trait> From<PostHistoryRecord<T>> for FlatPostHistoryRecord<T::AccountId, T::BlockNumber> {
+    fn from(from: PostHistoryRecord<T>) -> Self {
+        let PostHistoryRecord { edited, old_data } = from;
+
+        Self {
+            edited_by: edited.account,
+            edited_at_block: edited.block,
+            edited_at_time: edited.time.saturated_into::<u64>(),
+            old_content: old_data.content,
+            old_hidden: old_data.hidden,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Get a page of post edit history records starting from the latest one.
+    pub fn get_post_edit_history(
+        post_id: PostId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPostHistoryRecord<T::AccountId, T::BlockNumber>> {
+        Self::edit_history(post_id)
+            .into_iter()
+            .rev()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|record| record.into())
+            .collect()
+    }
+}
@@ -0,0 +1,120 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    ensure,
+    dispatch::DispatchResult,
+    traits::Get
+};
+use sp_std::prelude::*;
+use frame_system::{self as system, ensure_signed};
+
+use df_traits::moderation::IsAccountBlocked;
+use pallet_posts::{Module as Posts, PostById};
+use pallet_utils::{Error as UtilsError, PostId};
+
+/// The pallet's configuration trait.
+pub trait Trait: system::Trait
+    + pallet_utils::Trait
+    + pallet_posts::Trait
+{
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+}
+
+decl_error! {
+  pub enum Error for Module<T: Trait> {
+    /// The current post owner cannot transfer ownership to themself.
+    CannotTransferToCurrentOwner,
+    /// Account is already an owner of a post.
+    AlreadyAPostOwner,
+    /// There is no pending ownership transfer for a given post.
+    NoPendingTransferOnPost,
+    /// Account is not allowed to accept ownership transfer.
+    NotAllowedToAcceptOwnershipTransfer,
+    /// Account is not allowed to reject ownership transfer.
+    NotAllowedToRejectOwnershipTransfer,
+  }
+}
+
+// This pallet's storage items.
+decl_storage! {
+    trait Store for Module<T: Trait> as PostOwnershipModule {
+        pub PendingPostOwner get(fn pending_post_owner):
+            map hasher(twox_64_concat) PostId => Option<T::AccountId>;
+    }
+}
+
+decl_event!(
+    pub enum Event<T> where
+        <T as system::Trait>::AccountId,
+    {
+        PostOwnershipTransferCreated(/* current owner */ AccountId, PostId, /* new owner */ AccountId),
+        PostOwnershipTransferAccepted(AccountId, PostId),
+        PostOwnershipTransferRejected(AccountId, PostId),
+    }
+);
+
+// The pallet's dispatchable functions.
+decl_module! {
+  pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+
+    // Initializing errors
+    type Error = Error<T>;
+
+    // Initializing events
+    fn deposit_event() = default;
+
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+    pub fn transfer_post_ownership(origin, post_id: PostId, transfer_to: T::AccountId) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      let post = Posts::<T>::require_post(post_id)?;
+      post.ensure_owner(&who)?;
+
+      ensure!(who != transfer_to, Error::<T>::CannotTransferToCurrentOwner);
+      if let Some(space_id) = post.try_get_space_id() {
+        ensure!(T::IsAccountBlocked::is_allowed_account(transfer_to.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
+      }
+
+      <PendingPostOwner<T>>::insert(post_id, transfer_to.clone());
+
+      Self::deposit_event(RawEvent::PostOwnershipTransferCreated(who, post_id, transfer_to));
+      Ok(())
+    }
+
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
+    pub fn accept_pending_ownership(origin, post_id: PostId) -> DispatchResult {
+      let new_owner = ensure_signed(origin)?;
+
+      let mut post = Posts::<T>::require_post(post_id)?;
+      ensure!(!post.is_owner(&new_owner), Error::<T>::AlreadyAPostOwner);
+
+      let transfer_to = Self::pending_post_owner(post_id).ok_or(Error::<T>::NoPendingTransferOnPost)?;
+      ensure!(new_owner == transfer_to, Error::<T>::NotAllowedToAcceptOwnershipTransfer);
+
+      // Here we know that the origin is eligible to become a new owner of this post.
+      Posts::<T>::change_post_owner(&mut post, new_owner.clone())?;
+      <PostById<T>>::insert(post_id, post);
+
+      <PendingPostOwner<T>>::remove(post_id);
+
+      Self::deposit_event(RawEvent::PostOwnershipTransferAccepted(new_owner, post_id));
+      Ok(())
+    }
+
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+    pub fn reject_pending_ownership(origin, post_id: PostId) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      let post = Posts::<T>::require_post(post_id)?;
+      let transfer_to = Self::pending_post_owner(post_id).ok_or(Error::<T>::NoPendingTransferOnPost)?;
+      ensure!(who == transfer_to || post.is_owner(&who), Error::<T>::NotAllowedToRejectOwnershipTransfer);
+
+      <PendingPostOwner<T>>::remove(post_id);
+
+      Self::deposit_event(RawEvent::PostOwnershipTransferRejected(who, post_id));
+      Ok(())
+    }
+  }
+}
@@ -4,7 +4,7 @@ use codec::Codec;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
 
-use pallet_posts::rpc::{FlatPost, FlatPostKind, RepliesByPostId};
+use pallet_posts::rpc::{FeedOptions, FlatPost, FlatPostKind, FlatPostMention, RepliesByPostId};
 use pallet_utils::{PostId, SpaceId};
 
 sp_api::decl_runtime_apis! {
@@ -34,6 +34,37 @@ sp_api::decl_runtime_apis! {
 
         fn get_replies_by_parent_ids(parent_ids: Vec<PostId>, offset: u64, limit: u16) -> RepliesByPostId<AccountId, BlockNumber>;
 
+        fn get_post_ids_by_owner(account: AccountId) -> Vec<PostId>;
+
+        fn get_posts_by_owner(
+            account: AccountId,
+            kind_filter: Vec<FlatPostKind>,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
         fn get_feed(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_home_feed(
+            account: AccountId,
+            options: FeedOptions,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_top_posts_by_space_id(space_id: SpaceId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_hot_posts(
+            space_id: Option<SpaceId>,
+            kind_filter: Vec<FlatPostKind>,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_hot_feed(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_posts_by_tag(tag: Vec<u8>, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_mentions(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPostMention<AccountId, BlockNumber>>;
     }
 }
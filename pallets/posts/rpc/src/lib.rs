@@ -6,7 +6,7 @@ use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_api::ProvideRuntimeApi;
 
-use pallet_posts::rpc::{FlatPost, FlatPostKind, RepliesByPostId};
+use pallet_posts::rpc::{FeedOptions, FlatPost, FlatPostKind, FlatPostMention, RepliesByPostId};
 use pallet_utils::{PostId, SpaceId, rpc::map_rpc_error};
 pub use posts_runtime_api::PostsApi as PostsRuntimeApi;
 
@@ -97,6 +97,23 @@ pub trait PostsApi<BlockHash, AccountId, BlockNumber> {
     #[rpc(name = "posts_nextPostId")]
     fn get_next_post_id(&self, at: Option<BlockHash>) -> Result<PostId>;
 
+    #[rpc(name = "posts_getPostIdsByOwner")]
+    fn get_post_ids_by_owner(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+    ) -> Result<Vec<PostId>>;
+
+    #[rpc(name = "posts_getPostsByOwner")]
+    fn get_posts_by_owner(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
     #[rpc(name = "posts_getFeed")]
     fn get_feed(
         &self,
@@ -105,6 +122,62 @@ pub trait PostsApi<BlockHash, AccountId, BlockNumber> {
         offset: u64,
         limit: u16,
     ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getHomeFeed")]
+    fn get_home_feed(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        options: FeedOptions,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getTopPostsBySpaceId")]
+    fn get_top_posts_by_space_id(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getHotPosts")]
+    fn get_hot_posts(
+        &self,
+        at: Option<BlockHash>,
+        space_id: Option<SpaceId>,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getHotFeed")]
+    fn get_hot_feed(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getPostsByTag")]
+    fn get_posts_by_tag(
+        &self,
+        at: Option<BlockHash>,
+        tag: Vec<u8>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getMentions")]
+    fn get_mentions(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPostMention<AccountId, BlockNumber>>>;
 }
 
 pub struct Posts<C, M> {
@@ -254,6 +327,33 @@ where
         runtime_api_result.map_err(map_rpc_error)
     }
 
+    fn get_post_ids_by_owner(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+    ) -> Result<Vec<PostId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_post_ids_by_owner(&at, account);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_posts_by_owner(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_posts_by_owner(&at, account, kind_filter, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
     fn get_feed(
         &self,
         at: Option<<Block as BlockT>::Hash>,
@@ -267,4 +367,90 @@ where
         let runtime_api_result = api.get_feed(&at, account, offset, limit);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_home_feed(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        options: FeedOptions,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_home_feed(&at, account, options, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_top_posts_by_space_id(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_top_posts_by_space_id(&at, space_id, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_hot_posts(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: Option<SpaceId>,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_hot_posts(&at, space_id, kind_filter, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_hot_feed(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_hot_feed(&at, account, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_posts_by_tag(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        tag: Vec<u8>,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_posts_by_tag(&at, tag, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_mentions(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatPostMention<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_mentions(&at, account, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -165,12 +165,14 @@ impl<T: Trait> Post<T> {
     }
 }
 
-impl Default for PostUpdate {
+impl<AccountId> Default for PostUpdate<AccountId> {
     fn default() -> Self {
         PostUpdate {
             space_id: None,
             content: None,
-            hidden: None
+            hidden: None,
+            tags: None,
+            mentions: None,
         }
     }
 }
@@ -456,6 +458,12 @@ impl<T: Trait> Module<T> {
 
                 PostIdsBySpaceId::mutate(new_space_id, |post_ids| post_ids.push(post.id));
 
+                let tags = Self::tags_by_post_id(post.id);
+                if let Some(old_space_id) = old_space_id_opt {
+                    Self::unindex_post_tags(post.id, old_space_id, &tags);
+                }
+                Self::index_post_tags(post.id, new_space_id, &tags);
+
                 post.space_id = Some(new_space_id);
                 PostById::<T>::insert(post.id, post);
 
@@ -510,6 +518,7 @@ impl<T: Trait> Module<T> {
 
             post.space_id = None;
             PostIdsBySpaceId::mutate(space_id, |post_ids| remove_from_vec(post_ids, post_id));
+            Self::unindex_post_tags(post_id, space_id, &Self::tags_by_post_id(post_id));
         }
 
         PostById::<T>::insert(post.id, post);
@@ -517,6 +526,211 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Change an owner of a post, move a content deposit to a new owner
+    /// and keep the `PostIdsByOwner` index in sync.
+    /// This function does not save the post, so it should be inserted by a caller.
+    pub fn change_post_owner(post: &mut Post<T>, new_owner: T::AccountId) -> DispatchResult {
+        Utils::<T>::transfer_content_deposit(&post.owner, &new_owner, Self::content_deposit_by_post_id(post.id))?;
+
+        PostIdsByOwner::<T>::mutate(post.owner.clone(), |post_ids| remove_from_vec(post_ids, post.id));
+        PostIdsByOwner::<T>::mutate(new_owner.clone(), |post_ids| post_ids.push(post.id));
+        post.owner = new_owner;
+        Ok(())
+    }
+
+    /// Add up to `max_posts` posts that were created before `PostIdsByOwner` was added to this index.
+    /// Posts that are already in the index (e.g. created after the upgrade) are skipped.
+    pub fn index_post_ids_by_owner(max_posts: u64) -> Weight {
+        if Self::post_ids_by_owner_indexed() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let next_post_id = Self::next_post_id();
+        let start = Self::next_post_id_to_index_by_owner();
+        let end = start.saturating_add(max_posts).min(next_post_id);
+
+        let mut posts_indexed: Weight = 0;
+        for post_id in start..end {
+            if let Some(post) = Self::post_by_id(post_id) {
+                PostIdsByOwner::<T>::mutate(post.owner, |ids| {
+                    if !ids.contains(&post_id) {
+                        ids.push(post_id);
+                    }
+                });
+                posts_indexed = posts_indexed.saturating_add(1);
+            }
+        }
+
+        if end >= next_post_id {
+            PostIdsByOwnerIndexed::put(true);
+            NextPostIdToIndexByOwner::kill();
+        } else {
+            NextPostIdToIndexByOwner::put(end);
+        }
+
+        T::DbWeight::get().reads_writes(
+            3 + end.saturating_sub(start) + posts_indexed,
+            1 + posts_indexed
+        )
+    }
+
+    /// Lowercase tags, check their number, length and chars, and remove duplicates.
+    pub fn lowercase_and_validate_tags(tags: Vec<Tag>) -> Result<Vec<Tag>, DispatchError> {
+        ensure!(tags.len() <= T::MaxTagsPerPost::get() as usize, Error::<T>::TooManyTagsOnPost);
+
+        let mut unique_tags: Vec<Tag> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            ensure!(!tag.is_empty(), Error::<T>::TagIsEmpty);
+            ensure!(tag.len() <= T::MaxTagLen::get() as usize, Error::<T>::TagIsTooLong);
+
+            let tag = Utils::<T>::lowercase_handle(tag);
+            ensure!(
+                tag.iter().all(|&c| Utils::<T>::is_valid_handle_char(c)),
+                Error::<T>::TagContainsInvalidChars
+            );
+
+            if !unique_tags.contains(&tag) {
+                unique_tags.push(tag);
+            }
+        }
+
+        Ok(unique_tags)
+    }
+
+    /// Replace tags of a post and keep the tag indexes in sync.
+    /// Tags are indexed only if a post is in a space.
+    pub(crate) fn set_post_tags(post_id: PostId, space_id_opt: Option<SpaceId>, new_tags: Vec<Tag>) {
+        let old_tags = Self::tags_by_post_id(post_id);
+        if old_tags.is_empty() && new_tags.is_empty() {
+            return;
+        }
+
+        if let Some(space_id) = space_id_opt {
+            Self::unindex_post_tags(post_id, space_id, &old_tags);
+            Self::index_post_tags(post_id, space_id, &new_tags);
+        }
+
+        if new_tags.is_empty() {
+            TagsByPostId::remove(post_id);
+        } else {
+            TagsByPostId::insert(post_id, new_tags);
+        }
+    }
+
+    fn index_post_tags(post_id: PostId, space_id: SpaceId, tags: &[Tag]) {
+        for tag in tags {
+            Self::add_post_to_tag(tag, post_id);
+            PostIdsByTagInSpace::mutate(space_id, tag, |post_ids| post_ids.push(post_id));
+        }
+    }
+
+    fn unindex_post_tags(post_id: PostId, space_id: SpaceId, tags: &[Tag]) {
+        for tag in tags {
+            Self::remove_post_from_tag(tag, post_id);
+            PostIdsByTagInSpace::mutate(space_id, tag, |post_ids| remove_from_vec(post_ids, post_id));
+        }
+    }
+
+    fn add_post_to_tag(tag: &[u8], post_id: PostId) {
+        if PostIndexByTag::contains_key(tag, post_id) {
+            return;
+        }
+
+        let index = Self::posts_count_by_tag(tag);
+        PostIdByTagAndIndex::insert(tag, index, post_id);
+        PostIndexByTag::insert(tag, post_id, index);
+        PostsCountByTag::insert(tag, index.saturating_add(1));
+    }
+
+    /// Remove a post from the tag index and leave its slot empty,
+    /// so other tagged posts keep their order and indexes.
+    fn remove_post_from_tag(tag: &[u8], post_id: PostId) {
+        if let Some(index) = PostIndexByTag::take(tag, post_id) {
+            PostIdByTagAndIndex::remove(tag, index);
+        }
+    }
+
+    /// Ids of posts tagged with a tag, in the order of the tag index. Empty slots are skipped.
+    pub fn post_ids_by_tag(tag: Tag) -> Vec<PostId> {
+        (0..Self::posts_count_by_tag(&tag))
+            .filter_map(|index| Self::post_id_by_tag_and_index(&tag, index))
+            .collect()
+    }
+
+    /// Check the number of mentions and that mentioned accounts and spaces exist.
+    /// Duplicate mentions are removed.
+    pub fn validate_mentions(
+        mentions: Vec<Mention<T::AccountId>>
+    ) -> Result<Vec<Mention<T::AccountId>>, DispatchError> {
+        ensure!(mentions.len() <= T::MaxMentionsPerPost::get() as usize, Error::<T>::TooManyMentionsInPost);
+
+        let mut unique_mentions: Vec<Mention<T::AccountId>> = Vec::with_capacity(mentions.len());
+        for mention in mentions {
+            match &mention {
+                Mention::Account(account) => ensure!(
+                    system::Account::<T>::contains_key(account),
+                    Error::<T>::MentionedAccountNotFound
+                ),
+                Mention::Space(space_id) => ensure!(
+                    SpaceById::<T>::contains_key(space_id),
+                    Error::<T>::MentionedSpaceNotFound
+                ),
+            }
+
+            if !unique_mentions.contains(&mention) {
+                unique_mentions.push(mention);
+            }
+        }
+
+        Ok(unique_mentions)
+    }
+
+    /// Replace mentions of a post and notify accounts that were never notified about this post before,
+    /// even if they were mentioned in a previous version of it. A space mention notifies an owner
+    /// of the space. Accounts are not notified about their own mentions.
+    pub(crate) fn set_post_mentions(
+        mentioned_by: &T::AccountId,
+        post_id: PostId,
+        new_mentions: Vec<Mention<T::AccountId>>
+    ) {
+        let old_mentions = Self::mentions_by_post_id(post_id);
+        if old_mentions.is_empty() && new_mentions.is_empty() {
+            return;
+        }
+
+        for mention in new_mentions.iter().filter(|mention| !old_mentions.contains(mention)) {
+            let (account, space_id) = match mention {
+                Mention::Account(account) => (account.clone(), None),
+                Mention::Space(space_id) => match Spaces::<T>::space_by_id(space_id) {
+                    Some(space) => (space.owner, Some(*space_id)),
+                    None => continue,
+                },
+            };
+
+            if account == *mentioned_by || Self::account_mentioned_in_post(post_id, &account) {
+                continue;
+            }
+
+            let index = Self::mentions_count_by_account(&account);
+            MentionsByAccount::<T>::insert(&account, index, PostMention {
+                created: WhoAndWhen::<T>::new(mentioned_by.clone()),
+                post_id,
+                space_id,
+            });
+            MentionsCountByAccount::<T>::insert(&account, index.saturating_add(1));
+
+            AccountMentionedInPost::<T>::insert(post_id, &account, true);
+
+            Self::deposit_event(RawEvent::AccountMentioned(account, post_id));
+        }
+
+        if new_mentions.is_empty() {
+            MentionsByPostId::<T>::remove(post_id);
+        } else {
+            MentionsByPostId::<T>::insert(post_id, new_mentions);
+        }
+    }
+
     /// Rewrite ancestor counters when Post hidden status changes
     /// Warning: This will affect storage state!
     pub(crate) fn update_counters_on_comment_hidden_change(
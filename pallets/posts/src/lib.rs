@@ -19,6 +19,7 @@ use serde::{Serialize, Deserialize};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, fail,
     dispatch::{DispatchError, DispatchResult}, ensure, traits::Get,
+    weights::Weight,
 };
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
@@ -28,7 +29,7 @@ use df_traits::moderation::{IsAccountBlocked, IsContentBlocked, IsPostBlocked};
 use pallet_permissions::SpacePermission;
 use pallet_spaces::{Module as Spaces, Space, SpaceById};
 use pallet_utils::{
-    Module as Utils, Error as UtilsError,
+    Module as Utils, Error as UtilsError, BalanceOf,
     SpaceId, WhoAndWhen, Content, PostId
 };
 
@@ -81,13 +82,35 @@ pub struct Post<T: Trait> {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
-pub struct PostUpdate {
+pub struct PostUpdate<AccountId> {
     /// Deprecated: This field has no effect in `fn update_post()` extrinsic.
     /// See `fn move_post()` extrinsic if you want to move a post to another space.
     pub space_id: Option<SpaceId>,
 
     pub content: Option<Content>,
     pub hidden: Option<bool>,
+    pub tags: Option<Vec<Tag>>,
+    pub mentions: Option<Vec<Mention<AccountId>>>,
+}
+
+/// A normalized (lowercased) tag of a post, e.g. `substrate` or `web_3`.
+pub type Tag = Vec<u8>;
+
+/// An account or a space mentioned in a post, e.g. `@alice` or `@subsocial`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Mention<AccountId> {
+    Account(AccountId),
+    Space(SpaceId),
+}
+
+/// A record of a post that mentions an account, either directly
+/// or as an owner of a mentioned space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct PostMention<T: Trait> {
+    pub created: WhoAndWhen<T>,
+    pub post_id: PostId,
+    /// A space through which an account was mentioned, if any.
+    pub space_id: Option<SpaceId>,
 }
 
 /// Post extension provides specific information necessary for different kinds 
@@ -117,6 +140,7 @@ impl Default for PostExtension {
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
+    + pallet_profile_follows::Trait
     + pallet_space_follows::Trait
     + pallet_spaces::Trait
 {
@@ -128,9 +152,26 @@ pub trait Trait: system::Trait
 
     type PostScores: PostScores<Self>;
 
+    type AfterPostCreated: AfterPostCreated<Self>;
+
     type AfterPostUpdated: AfterPostUpdated<Self>;
 
     type IsPostBlocked: IsPostBlocked<PostId>;
+
+    /// Max number of tags on a single post.
+    type MaxTagsPerPost: Get<u16>;
+
+    /// Max length of a single tag.
+    type MaxTagLen: Get<u32>;
+
+    /// Max number of accounts and spaces mentioned in a single post.
+    type MaxMentionsPerPost: Get<u16>;
+
+    /// How many blocks of post age are equivalent to doubling of post points in a hot ranking.
+    type HotPointsDoublingBlocks: Get<Self::BlockNumber>;
+
+    /// Posts created more than this number of blocks ago are not included in a hot ranking.
+    type HotPostsWindowBlocks: Get<Self::BlockNumber>;
 }
 
 pub trait PostScores<T: Trait> {
@@ -147,13 +188,22 @@ impl<T: Trait> PostScores<T> for () {
     }
 }
 
+/// Handler that will be called after a post, comment or sharing post is created.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait AfterPostCreated<T: Trait> {
+    fn after_post_created(post: &Post<T>);
+}
+
 #[impl_trait_for_tuples::impl_for_tuples(10)]
 pub trait AfterPostUpdated<T: Trait> {
-    fn after_post_updated(account: T::AccountId, post: &Post<T>, old_data: PostUpdate);
+    fn after_post_updated(account: T::AccountId, post: &Post<T>, old_data: PostUpdate<T::AccountId>);
 }
 
 pub const FIRST_POST_ID: u64 = 1;
 
+/// Max number of posts added to `PostIdsByOwner` by the backfill in a single block.
+pub const MAX_POSTS_TO_INDEX_BY_OWNER_PER_BLOCK: u64 = 100;
+
 // This pallet's storage items.
 decl_storage! {
     trait Store for Module<T: Trait> as PostsModule {
@@ -169,9 +219,68 @@ decl_storage! {
         pub PostIdsBySpaceId get(fn post_ids_by_space_id):
             map hasher(twox_64_concat) SpaceId => Vec<PostId>;
 
+        /// Ids of all posts, comments and shares owned by an account.
+        pub PostIdsByOwner get(fn post_ids_by_owner):
+            map hasher(blake2_128_concat) T::AccountId => Vec<PostId>;
+
+        /// Whether `PostIdsByOwner` has been filled with posts created before this index was added.
+        pub PostIdsByOwnerIndexed get(fn post_ids_by_owner_indexed): bool;
+
+        /// The next post id to be added to `PostIdsByOwner` by the backfill.
+        pub NextPostIdToIndexByOwner get(fn next_post_id_to_index_by_owner): PostId = FIRST_POST_ID;
+
         // TODO rename 'Shared...' to 'Sharing...'
         pub SharedPostIdsByOriginalPostId get(fn shared_post_ids_by_original_post_id):
             map hasher(twox_64_concat) PostId => Vec<PostId>;
+
+        /// Normalized tags of a root post.
+        pub TagsByPostId get(fn tags_by_post_id):
+            map hasher(twox_64_concat) PostId => Vec<Tag>;
+
+        /// The number of slots in `PostIdByTagAndIndex` ever taken by root posts tagged with a tag.
+        /// Slots of untagged posts stay empty, so it can be greater than the number of tagged posts.
+        pub PostsCountByTag get(fn posts_count_by_tag):
+            map hasher(blake2_128_concat) Tag => u32;
+
+        /// Root posts tagged with a tag by their index from `0` to `PostsCountByTag`
+        /// in the order they were tagged.
+        pub PostIdByTagAndIndex get(fn post_id_by_tag_and_index): double_map
+            hasher(blake2_128_concat) Tag,
+            hasher(twox_64_concat) u32
+            => Option<PostId>;
+
+        /// An index of a tagged post in `PostIdByTagAndIndex`.
+        pub PostIndexByTag get(fn post_index_by_tag): double_map
+            hasher(blake2_128_concat) Tag,
+            hasher(twox_64_concat) PostId
+            => Option<u32>;
+
+        /// Ids of root posts in a space tagged with a tag.
+        pub PostIdsByTagInSpace get(fn post_ids_by_tag_in_space):
+            double_map hasher(twox_64_concat) SpaceId, hasher(blake2_128_concat) Tag => Vec<PostId>;
+
+        /// Accounts and spaces mentioned in a post or comment.
+        pub MentionsByPostId get(fn mentions_by_post_id):
+            map hasher(twox_64_concat) PostId => Vec<Mention<T::AccountId>>;
+
+        /// The number of times an account was mentioned. It is also an index of the next mention.
+        pub MentionsCountByAccount get(fn mentions_count_by_account):
+            map hasher(blake2_128_concat) T::AccountId => u64;
+
+        /// Mentions of an account by their sequential index, starting from zero.
+        pub MentionsByAccount get(fn mention_by_account):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) u64 => Option<PostMention<T>>;
+
+        /// Whether an account has been notified about a mention in a post.
+        /// An account is notified about mentions in the same post only once.
+        pub AccountMentionedInPost get(fn account_mentioned_in_post): double_map
+            hasher(twox_64_concat) PostId,
+            hasher(blake2_128_concat) T::AccountId
+            => bool;
+
+        /// A deposit reserved from the post owner for the `Raw` content of a post.
+        pub ContentDepositByPostId get(fn content_deposit_by_post_id):
+            map hasher(twox_64_concat) PostId => BalanceOf<T>;
     }
 }
 
@@ -184,6 +293,7 @@ decl_event!(
         PostDeleted(AccountId, PostId),
         PostShared(AccountId, PostId),
         PostMoved(AccountId, PostId),
+        AccountMentioned(AccountId, PostId),
     }
 );
 
@@ -231,6 +341,28 @@ decl_error! {
         /// This post's extension is not a `Comment`.
         NotComment,
 
+        // Tags related errors:
+
+        /// Post has more tags than allowed.
+        TooManyTagsOnPost,
+        /// Tag is empty.
+        TagIsEmpty,
+        /// Tag is longer than allowed.
+        TagIsTooLong,
+        /// Tag contains invalid characters.
+        TagContainsInvalidChars,
+        /// Only root posts can be tagged.
+        CannotTagComment,
+
+        // Mentions related errors:
+
+        /// Post mentions more accounts and spaces than allowed.
+        TooManyMentionsInPost,
+        /// Mentioned account does not exist.
+        MentionedAccountNotFound,
+        /// Mentioned space was not found by id.
+        MentionedSpaceNotFound,
+
         // Permissions related errors:
 
         /// User has no permission to create root posts in this space.
@@ -253,18 +385,37 @@ decl_module! {
 
     const MaxCommentDepth: u32 = T::MaxCommentDepth::get();
 
+    const MaxTagsPerPost: u16 = T::MaxTagsPerPost::get();
+
+    const MaxTagLen: u32 = T::MaxTagLen::get();
+
+    const MaxMentionsPerPost: u16 = T::MaxMentionsPerPost::get();
+
+    const HotPointsDoublingBlocks: T::BlockNumber = T::HotPointsDoublingBlocks::get();
+
+    const HotPostsWindowBlocks: T::BlockNumber = T::HotPostsWindowBlocks::get();
+
     // Initializing errors
     type Error = Error<T>;
 
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(8, 8)]
+    fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+      Self::index_post_ids_by_owner(MAX_POSTS_TO_INDEX_BY_OWNER_PER_BLOCK)
+    }
+
+    #[weight = 100_000 + T::DbWeight::get().reads_writes(
+      8 + 2 * tags.len() as u64 + 3 * mentions.len() as u64,
+      12 + 4 * tags.len() as u64 + 3 * mentions.len() as u64
+    )]
     pub fn create_post(
       origin,
       space_id_opt: Option<SpaceId>,
       extension: PostExtension,
-      content: Content
+      content: Content,
+      tags: Vec<Tag>,
+      mentions: Vec<Mention<T::AccountId>>
     ) -> DispatchResult {
       let creator = ensure_signed(origin)?;
 
@@ -273,6 +424,11 @@ decl_module! {
       let new_post_id = Self::next_post_id();
       let new_post: Post<T> = Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
 
+      let tags = Self::lowercase_and_validate_tags(tags)?;
+      ensure!(tags.is_empty() || !new_post.is_comment(), Error::<T>::CannotTagComment);
+
+      let mentions = Self::validate_mentions(mentions)?;
+
       // Get space from either space_id_opt or Comment if a comment provided
       let space = &mut new_post.get_space()?;
       ensure!(!space.hidden, Error::<T>::CannotCreateInHiddenScope);
@@ -299,6 +455,8 @@ decl_module! {
         error_on_permission_failed.into()
       )?;
 
+      Utils::<T>::ensure_can_reserve_content_deposit(&creator, &new_post.content)?;
+
       match extension {
         PostExtension::RegularPost => space.inc_posts(),
         PostExtension::SharedPost(post_id) => Self::create_sharing_post(&creator, new_post_id, post_id, space)?,
@@ -308,22 +466,41 @@ decl_module! {
       if new_post.is_root_post() {
         SpaceById::insert(space.id, space.clone());
         PostIdsBySpaceId::mutate(space.id, |ids| ids.push(new_post_id));
+        Self::set_post_tags(new_post_id, Some(space.id), tags);
       }
 
-      PostById::insert(new_post_id, new_post);
+      let content_deposit = Utils::<T>::reserve_content_deposit(&creator, &new_post.content)?;
+      ContentDepositByPostId::<T>::insert(new_post_id, content_deposit);
+
+      PostIdsByOwner::<T>::mutate(creator.clone(), |ids| ids.push(new_post_id));
+      PostById::insert(new_post_id, new_post.clone());
       NextPostId::mutate(|n| { *n += 1; });
 
-      Self::deposit_event(RawEvent::PostCreated(creator, new_post_id));
+      T::AfterPostCreated::after_post_created(&new_post);
+
+      Self::deposit_event(RawEvent::PostCreated(creator.clone(), new_post_id));
+      Self::set_post_mentions(&creator, new_post_id, mentions);
       Ok(())
     }
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(5, 3)]
-    pub fn update_post(origin, post_id: PostId, update: PostUpdate) -> DispatchResult {
+    #[weight = {
+      // Old tags are unindexed only if new tags are provided.
+      let tags_len = update.tags.as_ref().map_or(0, |tags| tags.len() + T::MaxTagsPerPost::get() as usize) as u64;
+      let mentions_len = update.mentions.as_ref().map_or(0, |mentions| mentions.len()) as u64;
+
+      100_000 + T::DbWeight::get().reads_writes(
+        6 + 2 * tags_len + 3 * mentions_len,
+        4 + 4 * tags_len + 3 * mentions_len
+      )
+    }]
+    pub fn update_post(origin, post_id: PostId, update: PostUpdate<T::AccountId>) -> DispatchResult {
       let editor = ensure_signed(origin)?;
 
       let has_updates =
         update.content.is_some() ||
-        update.hidden.is_some();
+        update.hidden.is_some() ||
+        update.tags.is_some() ||
+        update.mentions.is_some();
 
       ensure!(has_updates, Error::<T>::NoUpdatesForPost);
 
@@ -349,6 +526,9 @@ decl_module! {
             );
           }
 
+          // A deposit for raw content is always reserved from the post owner.
+          Utils::<T>::ensure_can_reserve_content_deposit(&post.owner, &content)?;
+
           old_data.content = Some(post.content.clone());
           post.content = content;
           is_update_applied = true;
@@ -377,8 +557,44 @@ decl_module! {
         }
       }
 
+      let mut new_tags_opt = None;
+      if let Some(tags) = update.tags {
+        let tags = Self::lowercase_and_validate_tags(tags)?;
+        let old_tags = Self::tags_by_post_id(post_id);
+
+        if tags != old_tags {
+          ensure!(!post.is_comment(), Error::<T>::CannotTagComment);
+
+          old_data.tags = Some(old_tags);
+          new_tags_opt = Some(tags);
+          is_update_applied = true;
+        }
+      }
+
+      let mut new_mentions_opt = None;
+      if let Some(mentions) = update.mentions {
+        let mentions = Self::validate_mentions(mentions)?;
+        let old_mentions = Self::mentions_by_post_id(post_id);
+
+        if mentions != old_mentions {
+          old_data.mentions = Some(old_mentions);
+          new_mentions_opt = Some(mentions);
+          is_update_applied = true;
+        }
+      }
+
       // Update this post only if at least one field should be updated:
       if is_update_applied {
+        if old_data.content.is_some() {
+          let old_deposit = Self::content_deposit_by_post_id(post_id);
+          let new_deposit = Utils::<T>::replace_content_deposit(&post.owner, old_deposit, &post.content)?;
+          ContentDepositByPostId::<T>::insert(post_id, new_deposit);
+        }
+
+        if let Some(new_tags) = new_tags_opt {
+          Self::set_post_tags(post.id, post.space_id, new_tags);
+        }
+
         post.updated = Some(WhoAndWhen::<T>::new(editor.clone()));
 
         if let Some(space) = space_opt {
@@ -388,7 +604,11 @@ decl_module! {
         <PostById<T>>::insert(post.id, post.clone());
         T::AfterPostUpdated::after_post_updated(editor.clone(), &post, old_data);
 
-        Self::deposit_event(RawEvent::PostUpdated(editor, post_id));
+        Self::deposit_event(RawEvent::PostUpdated(editor.clone(), post_id));
+
+        if let Some(new_mentions) = new_mentions_opt {
+          Self::set_post_mentions(&editor, post_id, new_mentions);
+        }
       }
       Ok(())
     }
@@ -419,6 +639,8 @@ decl_module! {
         space_id: old_space_id,
         content: None,
         hidden: None,
+        tags: None,
+        mentions: None,
       };
 
       T::AfterPostUpdated::after_post_updated(who.clone(), &post, historical_data);
@@ -1,14 +1,21 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::{vec, prelude::*};
 
+use df_traits::moderation::IsAccountBlocked;
+use frame_support::{storage::IterableStorageDoubleMap, traits::Get};
+use frame_system::Module as SystemModule;
+use pallet_profile_follows::FollowedAccountsByAccount;
 use pallet_space_follows::Module as SpaceFollows;
 use pallet_spaces::Module as Spaces;
-use pallet_utils::{bool_to_option, PostId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}, SpaceId};
+use pallet_utils::{
+    bool_to_option, log_2, Module as Utils, PostId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}, SpaceId
+};
 
-use crate::{Module, Post, PostExtension, FIRST_POST_ID, Trait};
+use crate::{Module, Post, PostExtension, PostMention, FIRST_POST_ID, Trait};
 pub type RepliesByPostId<AccountId, BlockNumber> = BTreeMap<PostId, Vec<FlatPost<AccountId, BlockNumber>>>;
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -87,6 +94,31 @@ pub struct FlatPost<AccountId, BlockNumber> {
     pub score: i32,
 }
 
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPostMention<AccountId, BlockNumber> {
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub who_and_when: FlatWhoAndWhen<AccountId, BlockNumber>,
+
+    pub post_id: PostId,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub space_id: Option<SpaceId>,
+}
+
+impl<T: Trait> From<PostMention<T>> for FlatPostMention<T::AccountId, T::BlockNumber> {
+    fn from(from: PostMention<T>) -> Self {
+        let PostMention { created, post_id, space_id } = from;
+
+        Self {
+            who_and_when: created.into(),
+            post_id,
+            space_id,
+        }
+    }
+}
+
 #[derive(Encode, Decode, Ord, PartialOrd, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FlatPostKind {
@@ -95,6 +127,19 @@ pub enum FlatPostKind {
     SharedPost
 }
 
+/// Options of a home feed. By default, a feed contains only posts from followed spaces.
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase", default))]
+pub struct FeedOptions {
+    /// Include root posts and shares authored by accounts followed by a feed owner.
+    pub include_followed_accounts: bool,
+    /// Exclude posts that are in hidden spaces.
+    pub exclude_hidden_spaces: bool,
+    /// Exclude posts whose authors are blocked in a space of a post.
+    pub exclude_blocked_authors: bool,
+}
+
 impl<T: Trait> From<Post<T>> for FlatPostKind {
     fn from(from: Post<T>) -> Self {
         match from.extension {
@@ -141,7 +186,8 @@ impl<T: Trait> Module<T> {
     ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
         let mut posts = Vec::new();
 
-        let (_, posts_ids) = all_post_ids.split_at(offset as usize);
+        let offset = (offset as usize).min(all_post_ids.len());
+        let (_, posts_ids) = all_post_ids.split_at(offset);
 
         for post_id in posts_ids.iter() {
             if let Ok(post) = Self::require_post(*post_id) {
@@ -271,6 +317,30 @@ impl<T: Trait> Module<T> {
         posts
     }
 
+    pub fn get_post_ids_by_owner(account: T::AccountId) -> Vec<PostId> {
+        Self::post_ids_by_owner(account)
+    }
+
+    /// Get a page of public posts, comments and shares owned by `account` in a descending order.
+    /// If `kind_filter` is empty, posts of any kind are returned.
+    pub fn get_posts_by_owner(
+        account: T::AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let no_filter = kind_filter.is_empty();
+        let kind_filter_set: BTreeSet<_> = kind_filter.into_iter().collect();
+
+        let mut post_ids = Self::post_ids_by_owner(account);
+        post_ids.sort_by(|a, b| b.cmp(a));
+
+        Self::get_posts_by_ids_with_filter(post_ids, offset, limit, |post| {
+            let kind: FlatPostKind = post.clone().into();
+            post.is_public() && (no_filter || kind_filter_set.contains(&kind))
+        })
+    }
+
     fn get_post_ids_by_space<F: FnMut(&Post<T>) -> bool>(space_id: SpaceId, mut filter: F) -> Vec<PostId> {
         Self::post_ids_by_space_id(space_id)
             .iter()
@@ -303,7 +373,7 @@ impl<T: Trait> Module<T> {
     }
 
     pub fn get_feed(account: T::AccountId, offset: u64, limit: u16) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
-        let mut post_ids: Vec<PostId> = SpaceFollows::<T>::spaces_followed_by_account(account)
+        let mut post_ids: Vec<PostId> = SpaceFollows::<T>::get_space_ids_followed_by_account(account)
             .iter()
             .flat_map(Self::post_ids_by_space_id)
             .collect();
@@ -313,4 +383,199 @@ impl<T: Trait> Module<T> {
 
         Self::get_posts_by_ids_with_filter(post_ids, offset, limit, |post| post.is_public() && !post.is_comment())
     }
+
+    /// Get a page of public root posts from spaces followed by `account`
+    /// and, if enabled in `options`, root posts and shares by accounts followed by `account`.
+    pub fn get_home_feed(
+        account: T::AccountId,
+        options: FeedOptions,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let mut post_ids: BTreeSet<PostId> = SpaceFollows::<T>::get_space_ids_followed_by_account(account.clone())
+            .iter()
+            .flat_map(Self::post_ids_by_space_id)
+            .collect();
+
+        if options.include_followed_accounts {
+            for (followed_account, _) in FollowedAccountsByAccount::<T>::iter_prefix(account) {
+                post_ids.extend(Self::post_ids_by_owner(followed_account));
+            }
+        }
+
+        // Sort post ids in a descending order
+        let post_ids: Vec<PostId> = post_ids.into_iter().rev().collect();
+
+        Self::get_posts_by_ids_with_filter(post_ids, offset, limit, |post| {
+            if !post.is_public() || post.is_comment() {
+                return false;
+            }
+
+            match post.space_id {
+                Some(space_id) => {
+                    let is_in_hidden_space = options.exclude_hidden_spaces &&
+                        Spaces::<T>::space_by_id(space_id).map_or(true, |space| space.hidden);
+                    let is_blocked_author = options.exclude_blocked_authors &&
+                        T::IsAccountBlocked::is_blocked_account(post.owner.clone(), space_id);
+
+                    !is_in_hidden_space && !is_blocked_author
+                }
+                // A root post without a space has been deleted from its space
+                None => false,
+            }
+        })
+    }
+
+    /// Calculate a "hot" rank of a post. The more points a post has, the higher its rank is,
+    /// but every doubling of points is worth only `HotPointsDoublingBlocks` of post age,
+    /// so new posts outrank old ones with the same points.
+    pub fn hot_rank_of_post(post: &Post<T>) -> i64 {
+        let points = post.score as i64 + post.upvotes_count as i64 + post.replies_count as i64;
+
+        let abs_points = points.abs().min(u32::MAX as i64) as u32;
+        let order = log_2(abs_points).map_or(0, |log| log as i64 + 1);
+
+        let created_at: u64 = post.created.block.unique_saturated_into();
+        let doubling_blocks: u64 = T::HotPointsDoublingBlocks::get().unique_saturated_into();
+
+        points.signum() * order * doubling_blocks as i64 + created_at as i64
+    }
+
+    /// `post_ids` should be sorted in a descending order,
+    /// so we can stop as soon as we reach a post older than `HotPostsWindowBlocks`.
+    fn get_hot_posts_by_ids<F: FnMut(&Post<T>) -> bool>(
+        post_ids: impl Iterator<Item = PostId>,
+        offset: u64,
+        limit: u16,
+        mut filter: F,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let current_block: u64 = SystemModule::<T>::block_number().unique_saturated_into();
+        let window_blocks: u64 = T::HotPostsWindowBlocks::get().unique_saturated_into();
+        let oldest_block = current_block.saturating_sub(window_blocks);
+
+        let mut ranked_posts: Vec<(i64, Post<T>)> = Vec::new();
+
+        for post_id in post_ids {
+            if let Some(post) = Self::post_by_id(post_id) {
+                let created_at: u64 = post.created.block.unique_saturated_into();
+                if created_at < oldest_block { break; }
+
+                if filter(&post) {
+                    ranked_posts.push((Self::hot_rank_of_post(&post), post));
+                }
+            }
+        }
+
+        ranked_posts.sort_by(|(rank_a, a), (rank_b, b)| rank_b.cmp(rank_a).then(b.id.cmp(&a.id)));
+
+        ranked_posts.into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(_, post)| post.into())
+            .collect()
+    }
+
+    /// Get a page of public posts ranked by a hot rank.
+    /// If `space_id` is provided, only root posts of this space are ranked:
+    /// their comments are counted in the rank by `replies_count`.
+    /// If `kind_filter` is empty, posts of any kind are ranked.
+    pub fn get_hot_posts(
+        space_id: Option<SpaceId>,
+        kind_filter: Vec<FlatPostKind>,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let no_filter = kind_filter.is_empty();
+        let kind_filter_set: BTreeSet<_> = kind_filter.into_iter().collect();
+
+        let filter = |post: &Post<T>| {
+            let kind: FlatPostKind = post.clone().into();
+            post.is_public() && (no_filter || kind_filter_set.contains(&kind))
+        };
+
+        match space_id {
+            Some(space_id) => {
+                let mut post_ids = Self::post_ids_by_space_id(space_id);
+                post_ids.sort_by(|a, b| b.cmp(a));
+                Self::get_hot_posts_by_ids(post_ids.into_iter(), offset, limit, filter)
+            }
+            None => {
+                let post_ids = (FIRST_POST_ID..Self::next_post_id()).rev();
+                Self::get_hot_posts_by_ids(post_ids, offset, limit, filter)
+            }
+        }
+    }
+
+    /// Get a page of public root posts from spaces followed by `account` ranked by a hot rank.
+    pub fn get_hot_feed(account: T::AccountId, offset: u64, limit: u16) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let mut post_ids: Vec<PostId> = SpaceFollows::<T>::get_space_ids_followed_by_account(account)
+            .iter()
+            .flat_map(Self::post_ids_by_space_id)
+            .collect();
+
+        post_ids.sort_by(|a, b| b.cmp(a));
+
+        Self::get_hot_posts_by_ids(post_ids.into_iter(), offset, limit, |post| post.is_public() && !post.is_comment())
+    }
+
+    /// Get a page of public root posts in a space sorted by score in a descending order.
+    pub fn get_top_posts_by_space_id(
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let public_space = Spaces::<T>::require_space(space_id).ok().filter(|space| space.is_public());
+        if public_space.is_none() {
+            return vec![]
+        }
+
+        let mut posts: Vec<Post<T>> = Self::post_ids_by_space_id(space_id)
+            .iter()
+            .filter_map(Self::post_by_id)
+            .filter(|post| post.is_public() && !post.is_comment())
+            .collect();
+
+        posts.sort_by(|a, b| b.score.cmp(&a.score).then(b.id.cmp(&a.id)));
+
+        posts.into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|post| post.into())
+            .collect()
+    }
+
+    /// Get a page of public posts tagged with `tag` starting from the latest tagged one.
+    /// `offset` and `limit` are counted in slots of the tag index, so pages stay stable when
+    /// posts are untagged, but a page can have fewer posts than `limit`.
+    pub fn get_posts_by_tag(
+        tag: Vec<u8>,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let tag = Utils::<T>::lowercase_handle(tag);
+        let end = (Self::posts_count_by_tag(&tag) as u64).saturating_sub(offset);
+        let start = end.saturating_sub(limit as u64);
+
+        (start..end).rev()
+            .filter_map(|index| Self::post_id_by_tag_and_index(&tag, index as u32))
+            .filter_map(Self::post_by_id)
+            .filter(|post| post.is_public())
+            .map(|post| post.into())
+            .collect()
+    }
+
+    /// Get a page of mentions of `account` starting from the latest one.
+    pub fn get_mentions(
+        account: T::AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPostMention<T::AccountId, T::BlockNumber>> {
+        let end = Self::mentions_count_by_account(&account).saturating_sub(offset);
+        let start = end.saturating_sub(limit as u64);
+
+        (start..end).rev()
+            .filter_map(|index| Self::mention_by_account(&account, index))
+            .map(|mention| mention.into())
+            .collect()
+    }
 }
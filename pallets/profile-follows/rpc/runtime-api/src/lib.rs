@@ -8,5 +8,9 @@ sp_api::decl_runtime_apis! {
         AccountId: Codec
     {
         fn filter_followed_accounts(account: AccountId, maybe_following: Vec<AccountId>) -> Vec<AccountId>;
+
+        fn get_account_followers(account: AccountId, offset: u64, limit: u16) -> Vec<AccountId>;
+
+        fn get_accounts_followed_by_account(account: AccountId, offset: u64, limit: u16) -> Vec<AccountId>;
     }
 }
@@ -18,6 +18,24 @@ pub trait ProfileFollowsApi<BlockHash, AccountId> {
         account: AccountId,
         maybe_following: Vec<AccountId>,
     ) -> Result<Vec<AccountId>>;
+
+    #[rpc(name = "profileFollows_getAccountFollowers")]
+    fn get_account_followers(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<AccountId>>;
+
+    #[rpc(name = "profileFollows_getAccountsFollowedByAccount")]
+    fn get_accounts_followed_by_account(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<AccountId>>;
 }
 
 pub struct ProfileFollows<C, M> {
@@ -54,4 +72,32 @@ where
         let runtime_api_result = api.filter_followed_accounts(&at, account, maybe_following);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_account_followers(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_account_followers(&at, account, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_accounts_followed_by_account(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_accounts_followed_by_account(&at, account, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -3,16 +3,20 @@
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure,
     dispatch::DispatchResult,
-    traits::Get
+    storage::IterableStorageMap,
+    traits::Get,
+    weights::Weight,
 };
-use sp_std::prelude::*;
+use sp_std::{cmp::min, prelude::*};
 use frame_system::{self as system, ensure_signed};
 
 use pallet_profiles::{Module as Profiles, SocialAccountById};
-use pallet_utils::remove_from_vec;
 
 pub mod rpc;
 
+/// Max number of deprecated follower list entries moved to double maps in a single block.
+pub const MAX_FOLLOWERS_TO_MIGRATE_PER_BLOCK: usize = 1_000;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
@@ -29,14 +33,27 @@ pub trait Trait: system::Trait
 // This pallet's storage items.
 decl_storage! {
     trait Store for Module<T: Trait> as ProfileFollowsModule {
-        pub AccountFollowers get(fn account_followers):
-            map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+        /// Followers of an account (following => follower).
+        /// The number of followers is stored in `SocialAccount.followers_count`.
+        pub FollowersByAccount get(fn account_follower):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
 
         pub AccountFollowedByAccount get(fn account_followed_by_account):
             map hasher(blake2_128_concat) (T::AccountId, T::AccountId) => bool;
 
-        pub AccountsFollowedByAccount get(fn accounts_followed_by_account):
-            map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+        /// Accounts followed by an account (follower => following).
+        /// The number of followed accounts is stored in `SocialAccount.following_accounts_count`.
+        pub FollowedAccountsByAccount get(fn followed_account):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
+
+        /// Deprecated: replaced with `FollowersByAccount` and drained in `on_initialize`.
+        pub AccountFollowers: map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+
+        /// Deprecated: replaced with `FollowedAccountsByAccount` and drained in `on_initialize`.
+        pub AccountsFollowedByAccount: map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+
+        /// Whether the deprecated follower lists were fully moved to double maps.
+        pub FollowersMigrated get(fn followers_migrated): bool;
     }
 }
 
@@ -77,7 +94,16 @@ decl_module! {
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
+    fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+      if Self::followers_migrated() {
+        return T::DbWeight::get().reads(1);
+      }
+
+      Self::migrate_followers_to_double_maps(MAX_FOLLOWERS_TO_MIGRATE_PER_BLOCK)
+    }
+
+    // One read and two writes are for a notification of the followed account.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 6)]
     pub fn follow_account(origin, account: T::AccountId) -> DispatchResult {
       let follower = ensure_signed(origin)?;
 
@@ -96,8 +122,8 @@ decl_module! {
 
       <SocialAccountById<T>>::insert(follower.clone(), follower_account);
       <SocialAccountById<T>>::insert(account.clone(), followed_account);
-      <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |ids| ids.push(account.clone()));
-      <AccountFollowers<T>>::mutate(account.clone(), |ids| ids.push(follower.clone()));
+      <FollowedAccountsByAccount<T>>::insert(follower.clone(), account.clone(), true);
+      <FollowersByAccount<T>>::insert(account.clone(), follower.clone(), true);
       <AccountFollowedByAccount<T>>::insert((follower.clone(), account.clone()), true);
 
       Self::deposit_event(RawEvent::AccountFollowed(follower, account));
@@ -121,8 +147,8 @@ decl_module! {
 
       <SocialAccountById<T>>::insert(follower.clone(), follower_account);
       <SocialAccountById<T>>::insert(account.clone(), followed_account);
-      <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |account_ids| remove_from_vec(account_ids, account.clone()));
-      <AccountFollowers<T>>::mutate(account.clone(), |account_ids| remove_from_vec(account_ids, follower.clone()));
+      <FollowedAccountsByAccount<T>>::remove(follower.clone(), account.clone());
+      <FollowersByAccount<T>>::remove(account.clone(), follower.clone());
       <AccountFollowedByAccount<T>>::remove((follower.clone(), account.clone()));
 
       Self::deposit_event(RawEvent::AccountUnfollowed(follower, account));
@@ -131,13 +157,84 @@ decl_module! {
   }
 }
 
+impl<T: Trait> Module<T> {
+    /// Move up to `max_followers` entries of the deprecated `AccountFollowers` and `AccountsFollowedByAccount` lists
+    /// to the `FollowersByAccount` and `FollowedAccountsByAccount` double maps.
+    ///
+    /// Migrated entries are removed from the old lists, so the rest of a long list is migrated in the next blocks.
+    /// Entries of accounts that unfollowed an account before they were migrated are dropped.
+    /// `FollowersMigrated` is set once both old maps are empty.
+    pub fn migrate_followers_to_double_maps(max_followers: usize) -> Weight {
+        // An empty list is counted as one entry, so the number of lists is bounded too.
+        let mut entries: usize = 0;
+        let mut lists: Weight = 0;
+
+        while entries < max_followers {
+            let (following, mut followers) = match AccountFollowers::<T>::iter().next() {
+                Some(list) => list,
+                None => break,
+            };
+            lists += 1;
+
+            let rest = followers.split_off(min(followers.len(), max_followers - entries));
+            entries += followers.len().max(1);
+
+            for follower in followers {
+                if Self::account_followed_by_account((follower.clone(), following.clone())) {
+                    <FollowersByAccount<T>>::insert(following.clone(), follower, true);
+                }
+            }
+
+            if rest.is_empty() {
+                AccountFollowers::<T>::remove(&following);
+            } else {
+                AccountFollowers::<T>::insert(&following, rest);
+            }
+        }
+
+        while entries < max_followers {
+            let (follower, mut followed) = match AccountsFollowedByAccount::<T>::iter().next() {
+                Some(list) => list,
+                None => break,
+            };
+            lists += 1;
+
+            let rest = followed.split_off(min(followed.len(), max_followers - entries));
+            entries += followed.len().max(1);
+
+            for following in followed {
+                if Self::account_followed_by_account((follower.clone(), following.clone())) {
+                    <FollowedAccountsByAccount<T>>::insert(follower.clone(), following, true);
+                }
+            }
+
+            if rest.is_empty() {
+                AccountsFollowedByAccount::<T>::remove(&follower);
+            } else {
+                AccountsFollowedByAccount::<T>::insert(&follower, rest);
+            }
+        }
+
+        if entries < max_followers {
+            FollowersMigrated::put(true);
+        }
+
+        T::DbWeight::get().reads_writes(
+            3 + lists + entries as Weight,
+            1 + lists + entries as Weight
+        )
+    }
+}
+
 /// Handler that will be called right before the account is followed.
 pub trait BeforeAccountFollowed<T: Trait> {
     fn before_account_followed(follower: T::AccountId, follower_reputation: u32, following: T::AccountId) -> DispatchResult;
 }
 
-impl<T: Trait> BeforeAccountFollowed<T> for () {
-    fn before_account_followed(_follower: T::AccountId, _follower_reputation: u32, _following: T::AccountId) -> DispatchResult {
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<T: Trait> BeforeAccountFollowed<T> for Tuple {
+    fn before_account_followed(follower: T::AccountId, follower_reputation: u32, following: T::AccountId) -> DispatchResult {
+        for_tuples!( #( Tuple::before_account_followed(follower.clone(), follower_reputation, following.clone())?; )* );
         Ok(())
     }
 }
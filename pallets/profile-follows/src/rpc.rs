@@ -1,6 +1,7 @@
+use frame_support::storage::IterableStorageDoubleMap;
 use sp_std::prelude::*;
 
-use crate::{Module, Trait};
+use crate::{FollowedAccountsByAccount, FollowersByAccount, Module, Trait};
 
 impl<T: Trait> Module<T> {
     pub fn filter_followed_accounts(account: T::AccountId, maybe_following: Vec<T::AccountId>) -> Vec<T::AccountId> {
@@ -8,4 +9,20 @@ impl<T: Trait> Module<T> {
             .filter(|maybe_following| Self::account_followed_by_account((&account, maybe_following)))
             .cloned().collect()
     }
-}
\ No newline at end of file
+
+    pub fn get_account_followers(account: T::AccountId, offset: u64, limit: u16) -> Vec<T::AccountId> {
+        FollowersByAccount::<T>::iter_prefix(account)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(follower, _)| follower)
+            .collect()
+    }
+
+    pub fn get_accounts_followed_by_account(account: T::AccountId, offset: u64, limit: u16) -> Vec<T::AccountId> {
+        FollowedAccountsByAccount::<T>::iter_prefix(account)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(following, _)| following)
+            .collect()
+    }
+}
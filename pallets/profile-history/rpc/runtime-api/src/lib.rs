@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_profile_history::rpc::FlatProfileHistoryRecord;
+
+sp_api::decl_runtime_apis! {
+    pub trait ProfileHistoryApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_profile_edit_history(
+            account: AccountId,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatProfileHistoryRecord<AccountId, BlockNumber>>;
+    }
+}
@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{decl_module, decl_storage};
+use frame_support::{decl_module, decl_storage, traits::Get};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
 use frame_system::{self as system};
@@ -9,6 +9,8 @@ use frame_system::{self as system};
 use pallet_utils::WhoAndWhen;
 use pallet_profiles::{Profile, ProfileUpdate, AfterProfileUpdated};
 
+pub mod rpc;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
 pub struct ProfileHistoryRecord<T: Trait> {
     pub edited: WhoAndWhen<T>,
@@ -19,7 +21,11 @@ pub struct ProfileHistoryRecord<T: Trait> {
 pub trait Trait: system::Trait
     + pallet_utils::Trait
     + pallet_profiles::Trait
-{}
+{
+    /// Max number of edit history records stored per entity.
+    /// The oldest records are removed when this limit is reached.
+    type MaxHistoryRecords: Get<u16>;
+}
 
 // This pallet's storage items.
 decl_storage! {
@@ -30,7 +36,10 @@ decl_storage! {
 }
 
 decl_module! {
-  pub struct Module<T: Trait> for enum Call where origin: T::Origin {}
+  pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+    /// Max number of edit history records stored per entity.
+    const MaxHistoryRecords: u16 = T::MaxHistoryRecords::get();
+  }
 }
 
 impl<T: Trait> ProfileHistoryRecord<T> {
@@ -44,7 +53,14 @@ impl<T: Trait> ProfileHistoryRecord<T> {
 
 impl<T: Trait> AfterProfileUpdated<T> for Module<T> {
     fn after_profile_updated(sender: T::AccountId, _profile: &Profile<T>, old_data: ProfileUpdate) {
-        <EditHistory<T>>::mutate(sender.clone(), |ids|
-            ids.push(ProfileHistoryRecord::<T>::new(sender, old_data)));
+        <EditHistory<T>>::mutate(sender.clone(), |records| {
+            records.push(ProfileHistoryRecord::<T>::new(sender, old_data));
+
+            let max_records = T::MaxHistoryRecords::get() as usize;
+            if records.len() > max_records {
+                let records_to_remove = records.len() - max_records;
+                records.drain(..records_to_remove);
+            }
+        });
     }
 }
@@ -0,0 +1,51 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::SaturatedConversion;
+use sp_std::prelude::*;
+
+use pallet_utils::{Content, rpc::ShouldSkip};
+
+use crate::{Module, ProfileHistoryRecord, Trait};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatProfileHistoryRecord<AccountId, BlockNumber> {
+    pub edited_by: AccountId,
+    pub edited_at_block: BlockNumber,
+    pub edited_at_time: u64,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_content: Option<Content>,
+}
+
+impl<T: Trait> From<ProfileHistoryRecord<T>> for FlatProfileHistoryRecord<T::AccountId, T::BlockNumber> {
+    fn from(from: ProfileHistoryRecord<T>) -> Self {
+        let ProfileHistoryRecord { edited, old_data } = from;
+
+        Self {
+            edited_by: edited.account,
+            edited_at_block: edited.block,
+            edited_at_time: edited.time.saturated_into::<u64>(),
+            old_content: old_data.content,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Get a page of profile edit history records starting from the latest one.
+    pub fn get_profile_edit_history(
+        account: T::AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatProfileHistoryRecord<T::AccountId, T::BlockNumber>> {
+        Self::edit_history(account)
+            .into_iter()
+            .rev()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|record| record.into())
+            .collect()
+    }
+}
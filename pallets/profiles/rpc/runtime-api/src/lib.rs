@@ -6,6 +6,7 @@ use sp_std::vec::Vec;
 use pallet_profiles::rpc::FlatSocialAccount;
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait ProfilesApi<AccountId, BlockNumber> where
         AccountId: Codec,
         BlockNumber: Codec
@@ -13,5 +14,10 @@ sp_api::decl_runtime_apis! {
         fn get_social_accounts_by_ids(
             account_ids: Vec<AccountId>
         ) -> Vec<FlatSocialAccount<AccountId, BlockNumber>>;
+
+        fn get_top_accounts_by_reputation(
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatSocialAccount<AccountId, BlockNumber>>;
     }
 }
@@ -18,6 +18,14 @@ pub trait ProfilesApi<BlockHash, AccountId, BlockNumber> {
         at: Option<BlockHash>,
         account_ids: Vec<AccountId>,
     ) -> Result<Vec<FlatSocialAccount<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "profiles_getTopAccountsByReputation")]
+    fn get_top_accounts_by_reputation(
+        &self,
+        at: Option<BlockHash>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSocialAccount<AccountId, BlockNumber>>>;
 }
 
 pub struct Profiles<C, M> {
@@ -50,4 +58,17 @@ where
         let runtime_api_result = api.get_social_accounts_by_ids(&at, account_ids);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_top_accounts_by_reputation(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSocialAccount<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_top_accounts_by_reputation(&at, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -10,7 +10,7 @@ use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 use frame_system::{self as system, ensure_signed};
 
-use pallet_utils::{Module as Utils, WhoAndWhen, Content};
+use pallet_utils::{Module as Utils, BalanceOf, WhoAndWhen, Content};
 
 pub mod rpc;
 
@@ -50,6 +50,10 @@ decl_storage! {
     trait Store for Module<T: Trait> as ProfilesModule {
         pub SocialAccountById get(fn social_account_by_id):
             map hasher(blake2_128_concat) T::AccountId => Option<SocialAccount<T>>;
+
+        /// A deposit reserved from an account for the `Raw` content of its profile.
+        pub ProfileContentDepositByAccount get(fn profile_content_deposit_by_account):
+            map hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
     }
 }
 
@@ -84,7 +88,7 @@ decl_module! {
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(1, 2)]
+    #[weight = 100_000 + T::DbWeight::get().reads_writes(1, 3)]
     pub fn create_profile(origin, content: Content) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -93,6 +97,9 @@ decl_module! {
       let mut social_account = Self::get_or_new_social_account(owner.clone());
       ensure!(social_account.profile.is_none(), Error::<T>::ProfileAlreadyCreated);
 
+      let content_deposit = Utils::<T>::reserve_content_deposit(&owner, &content)?;
+      <ProfileContentDepositByAccount<T>>::insert(&owner, content_deposit);
+
       social_account.profile = Some(
         Profile {
           created: WhoAndWhen::<T>::new(owner.clone()),
@@ -106,7 +113,7 @@ decl_module! {
       Ok(())
     }
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(1, 2)]
+    #[weight = 100_000 + T::DbWeight::get().reads_writes(2, 3)]
     pub fn update_profile(origin, update: ProfileUpdate) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -129,6 +136,12 @@ decl_module! {
       }
 
       if is_update_applied {
+        if old_data.content.is_some() {
+          let old_deposit = Self::profile_content_deposit_by_account(&owner);
+          let new_deposit = Utils::<T>::replace_content_deposit(&owner, old_deposit, &profile.content)?;
+          <ProfileContentDepositByAccount<T>>::insert(&owner, new_deposit);
+        }
+
         profile.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
         social_account.profile = Some(profile.clone());
 
@@ -1,13 +1,17 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use frame_support::storage::IterableStorageMap;
 use sp_std::prelude::*;
 
 use pallet_utils::rpc::{FlatContent, FlatWhoAndWhen};
 
 use frame_system::Module as SystemModule;
 
-use crate::{Module, Profile, SocialAccount, Trait};
+use crate::{Module, Profile, SocialAccount, SocialAccountById, Trait};
+
+/// Max number of social accounts ranked by `get_top_accounts_by_reputation`.
+pub const MAX_ACCOUNTS_TO_RANK: usize = 10_000;
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -75,6 +79,29 @@ impl<T: Trait> Module<T> {
                    .collect()
     }
 
+    /// Get a page of social accounts sorted by reputation in a descending order and then by account id.
+    /// At most `MAX_ACCOUNTS_TO_RANK` social accounts are read from storage and ranked.
+    pub fn get_top_accounts_by_reputation(
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatSocialAccount<T::AccountId, T::BlockNumber>> {
+        let mut accounts: Vec<(T::AccountId, SocialAccount<T>)> = SocialAccountById::<T>::iter()
+            .take(MAX_ACCOUNTS_TO_RANK)
+            .collect();
+
+        accounts.sort_by(|(a_id, a), (b_id, b)| b.reputation.cmp(&a.reputation).then(a_id.cmp(b_id)));
+
+        accounts.into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(account, social_account)| {
+                let mut flat_social_account: FlatSocialAccount<T::AccountId, T::BlockNumber> = social_account.into();
+                flat_social_account.id = account;
+                flat_social_account
+            })
+            .collect()
+    }
+
     pub fn get_account_data(account: T::AccountId) -> T::AccountData {
         SystemModule::<T>::account(&account).data
     }
@@ -125,7 +125,8 @@ decl_module! {
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(6, 5)]
+    // One read and two writes of each `PostReactionScores` call are for a notification.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(7, 7)]
     pub fn create_post_reaction(origin, post_id: PostId, kind: ReactionKind) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -177,7 +178,7 @@ decl_module! {
       Ok(())
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 6)]
     pub fn update_post_reaction(origin, post_id: PostId, reaction_id: ReactionId, new_kind: ReactionKind) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -221,7 +222,7 @@ decl_module! {
       Ok(())
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 6)]
     pub fn delete_post_reaction(origin, post_id: PostId, reaction_id: ReactionId) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -285,8 +286,10 @@ pub trait PostReactionScores<T: Trait> {
     fn score_post_on_reaction(actor: T::AccountId, post: &mut Post<T>, reaction_kind: ReactionKind) -> DispatchResult;
 }
 
-impl<T: Trait> PostReactionScores<T> for () {
-    fn score_post_on_reaction(_actor: T::AccountId, _post: &mut Post<T>, _reaction_kind: ReactionKind) -> DispatchResult {
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<T: Trait> PostReactionScores<T> for Tuple {
+    fn score_post_on_reaction(actor: T::AccountId, post: &mut Post<T>, reaction_kind: ReactionKind) -> DispatchResult {
+        for_tuples!( #( Tuple::score_post_on_reaction(actor.clone(), post, reaction_kind)?; )* );
         Ok(())
     }
 }
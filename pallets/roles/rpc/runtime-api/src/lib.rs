@@ -2,17 +2,26 @@
 
 use codec::Codec;
 use sp_std::vec::Vec;
-use pallet_utils::SpaceId;
+use pallet_utils::{SpaceId, User};
 use pallet_permissions::SpacePermission;
+use pallet_roles::{RoleId, rpc::FlatRole};
 
 sp_api::decl_runtime_apis! {
-    pub trait RolesApi<AccountId> where
-        AccountId: Codec
+    #[api_version(2)]
+    pub trait RolesApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
     {
         fn get_space_permissions_by_account(account: AccountId, space_id: SpaceId) -> Vec<SpacePermission>;
 
         fn get_accounts_with_any_role_in_space(space_id: SpaceId) -> Vec<AccountId>;
 
         fn get_space_ids_for_account_with_any_role(account_id: AccountId) -> Vec<SpaceId>;
+
+        fn get_roles_by_space_id(space_id: SpaceId, offset: u64, limit: u16) -> Vec<FlatRole<AccountId, BlockNumber>>;
+
+        fn get_users_by_role_id(role_id: RoleId, offset: u64, limit: u16) -> Vec<User<AccountId>>;
+
+        fn get_roles_by_user_in_space(user: User<AccountId>, space_id: SpaceId) -> Vec<FlatRole<AccountId, BlockNumber>>;
     }
 }
@@ -5,13 +5,14 @@ use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_api::ProvideRuntimeApi;
-use pallet_utils::{SpaceId, rpc::map_rpc_error};
+use pallet_utils::{SpaceId, User, rpc::map_rpc_error};
 use pallet_permissions::SpacePermission;
+use pallet_roles::{RoleId, rpc::FlatRole};
 
 pub use roles_runtime_api::RolesApi as RolesRuntimeApi;
 
 #[rpc]
-pub trait RolesApi<BlockHash, AccountId> {
+pub trait RolesApi<BlockHash, AccountId, BlockNumber> {
     #[rpc(name = "roles_getSpacePermissionsByAccount")]
     fn get_space_permissions_by_account(
         &self,
@@ -33,6 +34,32 @@ pub trait RolesApi<BlockHash, AccountId> {
         at: Option<BlockHash>,
         account_id: AccountId
     ) -> Result<Vec<SpaceId>>;
+
+    #[rpc(name = "roles_getRolesBySpaceId")]
+    fn get_roles_by_space_id(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatRole<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "roles_getUsersByRoleId")]
+    fn get_users_by_role_id(
+        &self,
+        at: Option<BlockHash>,
+        role_id: RoleId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<User<AccountId>>>;
+
+    #[rpc(name = "roles_getRolesByUserInSpace")]
+    fn get_roles_by_user_in_space(
+        &self,
+        at: Option<BlockHash>,
+        user: User<AccountId>,
+        space_id: SpaceId
+    ) -> Result<Vec<FlatRole<AccountId, BlockNumber>>>;
 }
 
 pub struct Roles<C, M> {
@@ -49,13 +76,14 @@ impl<C, M> Roles<C, M> {
     }
 }
 
-impl<C, Block, AccountId> RolesApi<<Block as BlockT>::Hash, AccountId>
+impl<C, Block, AccountId, BlockNumber> RolesApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
     for Roles<C, Block>
 where
     Block: BlockT,
     AccountId: Codec,
+    BlockNumber: Codec,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: RolesRuntimeApi<Block, AccountId>,
+    C::Api: RolesRuntimeApi<Block, AccountId, BlockNumber>,
 {
     fn get_space_permissions_by_account(
         &self, at:
@@ -93,4 +121,45 @@ where
         let runtime_api_result = api.get_space_ids_for_account_with_any_role(&at, account_id);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_roles_by_space_id(
+        &self, at:
+        Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<FlatRole<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_roles_by_space_id(&at, space_id, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_users_by_role_id(
+        &self, at:
+        Option<<Block as BlockT>::Hash>,
+        role_id: RoleId,
+        offset: u64,
+        limit: u16
+    ) -> Result<Vec<User<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_users_by_role_id(&at, role_id, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_roles_by_user_in_space(
+        &self, at:
+        Option<<Block as BlockT>::Hash>,
+        user: User<AccountId>,
+        space_id: SpaceId
+    ) -> Result<Vec<FlatRole<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_roles_by_user_in_space(&at, user, space_id);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
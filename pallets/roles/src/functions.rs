@@ -1,6 +1,6 @@
 use super::*;
 
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, storage::IterableStorageMap};
 use pallet_permissions::SpacePermissionsContext;
 
 impl<T: Trait> Module<T> {
@@ -17,6 +17,30 @@ impl<T: Trait> Module<T> {
       Ok(Self::role_by_id(role_id).ok_or(Error::<T>::RoleNotFound)?)
   }
 
+  /// Get `RoleTemplate` by id from the storage or return `RoleTemplateNotFound` error.
+  pub fn require_role_template(template_id: RoleTemplateId) -> Result<RoleTemplate<T>, DispatchError> {
+      Ok(Self::role_template_by_id(template_id).ok_or(Error::<T>::RoleTemplateNotFound)?)
+  }
+
+  /// Save a new role to the storage and increment the role id counter.
+  pub(crate) fn insert_new_role(new_role: &Role<T>) -> DispatchResult {
+    let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
+
+    // A deposit for raw content is reserved from the role creator.
+    let content_deposit = Utils::<T>::reserve_content_deposit(&new_role.created.account, &new_role.content)?;
+    <ContentDepositByRoleId<T>>::insert(new_role.id, (new_role.created.account.clone(), content_deposit));
+
+    NextRoleId::put(next_role_id);
+
+    <RoleById<T>>::insert(new_role.id, new_role.clone());
+    RoleIdsBySpaceId::mutate(new_role.space_id, |role_ids| { role_ids.push(new_role.id) });
+
+    if let Some(expires_at) = new_role.expires_at {
+      <RoleIdsByExpireBlock<T>>::mutate(expires_at, |role_ids| role_ids.push(new_role.id));
+    }
+    Ok(())
+  }
+
   /// Ensure that this account is not blocked and has 'ManageRoles' permission in a given space
   pub fn ensure_role_manager(account: T::AccountId, space_id: SpaceId) -> DispatchResult {
     ensure!(
@@ -31,6 +55,267 @@ impl<T: Trait> Module<T> {
     )
   }
 
+  /// Remove a role from all associated storage items and revoke it from its users.
+  /// If the role has too many users, the rest of them will be revoked in the next steps.
+  /// Returns the number of revoked users.
+  pub(crate) fn delete_role_from_storage(role: &Role<T>) -> u64 {
+    let role_id = role.id;
+
+    let role_idx_by_space_opt = Self::role_ids_by_space_id(role.space_id).iter()
+      .position(|x| { *x == role_id });
+
+    if let Some(role_idx) = role_idx_by_space_opt {
+      RoleIdsBySpaceId::mutate(role.space_id, |n| { n.swap_remove(role_idx) });
+    }
+
+    <RoleById<T>>::remove(role_id);
+    Self::move_role_in_expiry_index(role_id, role.expires_at, None);
+    if let Some((depositor, deposit)) = <ContentDepositByRoleId<T>>::take(role_id) {
+      Utils::<T>::unreserve_content_deposit(&depositor, deposit);
+    }
+
+    // Users that are not migrated yet should be revoked together with the rest of users.
+    Self::migrate_users_of_role(role_id);
+
+    Self::revoke_next_users_of_deleted_role(role_id, role.space_id)
+  }
+
+  /// Revoke a deleted role from up to `MaxUsersToProcessPerDeleteRole` of its last users.
+  /// Returns the number of revoked users.
+  pub(crate) fn revoke_next_users_of_deleted_role(role_id: RoleId, space_id: SpaceId) -> u64 {
+    let max_users = T::MaxUsersToProcessPerDeleteRole::get() as u64;
+
+    let mut revoked_users: u64 = 0;
+    while revoked_users < max_users {
+      let user = match Self::take_last_user_of_role(role_id) {
+        Some(user) => user,
+        None => break,
+      };
+
+      let mut role_ids = Self::role_ids_by_user_in_space(&user, space_id);
+      role_ids.retain(|id| *id != role_id);
+
+      if role_ids.is_empty() {
+        <RoleIdsByUserInSpace<T>>::remove(&user, space_id);
+      } else {
+        <RoleIdsByUserInSpace<T>>::insert(&user, space_id, role_ids);
+      }
+      revoked_users += 1;
+    }
+
+    if Self::users_count_by_role_id(role_id) == 0 {
+      UsersCountByRoleId::remove(role_id);
+      if PendingRoleDeletions::take(role_id).is_some() {
+        Self::deposit_event(RawEvent::RoleDeletionFinished(role_id));
+      }
+    } else {
+      PendingRoleDeletions::insert(role_id, space_id);
+    }
+
+    revoked_users
+  }
+
+  /// Get all users of a role in the order they were added, except for revoked users
+  /// that were replaced by the last user of a role.
+  pub fn users_by_role_id(role_id: RoleId) -> Vec<User<T::AccountId>> {
+    (0..Self::users_count_by_role_id(role_id))
+      .filter_map(|index| Self::user_by_role_id_and_index(role_id, index))
+      .collect()
+  }
+
+  /// Add a user to the end of the users of a role. Returns `false` if a user already has this role.
+  pub(crate) fn add_user_to_role(role_id: RoleId, user: &User<T::AccountId>) -> bool {
+    if <UserIndexByRoleId<T>>::contains_key(role_id, user) {
+      return false;
+    }
+
+    let index = Self::users_count_by_role_id(role_id);
+    <UserByRoleIdAndIndex<T>>::insert(role_id, index, user);
+    <UserIndexByRoleId<T>>::insert(role_id, user, index);
+    UsersCountByRoleId::insert(role_id, index.saturating_add(1));
+    true
+  }
+
+  /// Remove a user from the users of a role. The last user of a role takes its index.
+  pub(crate) fn remove_user_from_role(role_id: RoleId, user: &User<T::AccountId>) {
+    if let Some(index) = <UserIndexByRoleId<T>>::take(role_id, user) {
+      let last_index = Self::users_count_by_role_id(role_id).saturating_sub(1);
+
+      if let Some(last_user) = <UserByRoleIdAndIndex<T>>::take(role_id, last_index) {
+        if index != last_index {
+          <UserByRoleIdAndIndex<T>>::insert(role_id, index, &last_user);
+          <UserIndexByRoleId<T>>::insert(role_id, last_user, index);
+        }
+      }
+      UsersCountByRoleId::insert(role_id, last_index);
+    }
+  }
+
+  /// Remove the last user of a role without reading the rest of its users.
+  fn take_last_user_of_role(role_id: RoleId) -> Option<User<T::AccountId>> {
+    let users_count = Self::users_count_by_role_id(role_id);
+    if users_count == 0 {
+      return None;
+    }
+
+    let last_index = users_count - 1;
+    UsersCountByRoleId::insert(role_id, last_index);
+
+    let user = <UserByRoleIdAndIndex<T>>::take(role_id, last_index)?;
+    <UserIndexByRoleId<T>>::remove(role_id, &user);
+    Some(user)
+  }
+
+  /// Move users of a role from its deprecated `UsersByRoleId` list to `UserByRoleIdAndIndex`.
+  /// Returns the number of moved users.
+  pub(crate) fn migrate_users_of_role(role_id: RoleId) -> u64 {
+    let users = UsersByRoleId::<T>::take(role_id);
+    for user in users.iter() {
+      Self::add_user_to_role(role_id, user);
+    }
+    users.len() as u64
+  }
+
+  /// Move up to `max_lists` user lists of roles from the deprecated `UsersByRoleId` map
+  /// to `UserByRoleIdAndIndex`.
+  ///
+  /// Old lists are removed while migrating. `RoleUsersMigrated` is set once the old map is empty.
+  pub fn migrate_role_users(max_lists: usize) -> Weight {
+    if Self::role_users_migrated() {
+      return T::DbWeight::get().reads(1);
+    }
+
+    let mut lists: usize = 0;
+    let mut users_count: Weight = 0;
+
+    for (role_id, users) in UsersByRoleId::<T>::drain().take(max_lists) {
+      lists += 1;
+
+      // Users of a role deleted before the migration have been revoked already.
+      if !<RoleById<T>>::contains_key(role_id) {
+        continue;
+      }
+
+      users_count = users_count.saturating_add(users.len() as Weight);
+      for user in users.iter() {
+        Self::add_user_to_role(role_id, user);
+      }
+    }
+
+    if lists < max_lists {
+      RoleUsersMigrated::put(true);
+    }
+
+    T::DbWeight::get().reads_writes(
+      2 + lists as Weight + users_count,
+      1 + lists as Weight + users_count.saturating_mul(3)
+    )
+  }
+
+  /// Revoke the next chunk of users from any role which deletion is pending.
+  pub(crate) fn continue_any_pending_role_deletion() -> Weight {
+    match PendingRoleDeletions::iter().next() {
+      Some((role_id, space_id)) => {
+        let revoked_users = Self::revoke_next_users_of_deleted_role(role_id, space_id);
+        T::DbWeight::get().reads_writes(2 + revoked_users, 2 + revoked_users)
+      }
+      None => T::DbWeight::get().reads(1),
+    }
+  }
+
+  pub(crate) fn move_role_in_expiry_index(
+    role_id: RoleId,
+    old_expires_at: Option<T::BlockNumber>,
+    new_expires_at: Option<T::BlockNumber>,
+  ) {
+    if let Some(expires_at) = old_expires_at {
+      let mut role_ids = Self::role_ids_by_expire_block(expires_at);
+      role_ids.retain(|id| *id != role_id);
+
+      if role_ids.is_empty() {
+        <RoleIdsByExpireBlock<T>>::remove(expires_at);
+      } else {
+        <RoleIdsByExpireBlock<T>>::insert(expires_at, role_ids);
+      }
+    }
+
+    if let Some(expires_at) = new_expires_at {
+      <RoleIdsByExpireBlock<T>>::mutate(expires_at, |role_ids| role_ids.push(role_id));
+    }
+  }
+
+  /// Add up to `max_roles` roles that were created before `RoleIdsByExpireBlock` was added to this index.
+  /// Roles that have already expired are scheduled for deletion at `block_number`.
+  /// Roles that are already in the index (e.g. created after the upgrade) are skipped.
+  pub fn index_roles_by_expire_block(block_number: T::BlockNumber, max_roles: u64) -> Weight {
+    if Self::role_ids_by_expire_block_indexed() {
+      return T::DbWeight::get().reads(1);
+    }
+
+    let next_role_id = Self::next_role_id();
+    let start = Self::next_role_id_to_index_by_expire_block();
+    let end = start.saturating_add(max_roles).min(next_role_id);
+
+    let mut roles_indexed: Weight = 0;
+    for role_id in start..end {
+      if let Some(expires_at) = Self::role_by_id(role_id).and_then(|role| role.expires_at) {
+        if Self::role_ids_by_expire_block(expires_at).contains(&role_id) {
+          continue;
+        }
+
+        <RoleIdsByExpireBlock<T>>::mutate(expires_at.max(block_number), |role_ids| role_ids.push(role_id));
+        roles_indexed = roles_indexed.saturating_add(1);
+      }
+    }
+
+    if end >= next_role_id {
+      RoleIdsByExpireBlockIndexed::put(true);
+      NextRoleIdToIndexByExpireBlock::kill();
+    } else {
+      NextRoleIdToIndexByExpireBlock::put(end);
+    }
+
+    T::DbWeight::get().reads_writes(
+      3 + end.saturating_sub(start) * 2,
+      1 + roles_indexed
+    )
+  }
+
+  /// Delete roles that expire at this block.
+  /// If there are more expired roles than `MaxExpiredRolesToDeletePerBlock`,
+  /// the rest of them will be deleted in the next block.
+  pub(crate) fn delete_expired_roles(block_number: T::BlockNumber) -> Weight {
+    let mut role_ids = <RoleIdsByExpireBlock<T>>::take(block_number);
+    if role_ids.is_empty() {
+      return T::DbWeight::get().reads(1);
+    }
+
+    let max_roles = T::MaxExpiredRolesToDeletePerBlock::get() as usize;
+    if role_ids.len() > max_roles {
+      let postponed_role_ids = role_ids.split_off(max_roles);
+      <RoleIdsByExpireBlock<T>>::mutate(block_number + One::one(), |ids| ids.extend(postponed_role_ids));
+    }
+
+    let mut users_count: u64 = 0;
+    for role_id in role_ids.iter() {
+      if let Some(role) = Self::role_by_id(role_id) {
+        // A role could be renewed after it has been added to this block.
+        let is_expired = role.expires_at.map_or(false, |expires_at| expires_at <= block_number);
+        if is_expired {
+          let revoked_users = Self::delete_role_from_storage(&role);
+          users_count = users_count.saturating_add(revoked_users);
+          Self::deposit_event(RawEvent::RoleExpired(*role_id));
+        }
+      }
+    }
+
+    let roles_count = role_ids.len() as u64;
+    T::DbWeight::get().reads_writes(
+      2 + roles_count * 3 + users_count,
+      2 + roles_count * 4 + users_count
+    )
+  }
+
   fn ensure_user_has_space_permission_with_load_space(
     user: User<T::AccountId>,
     space_id: SpaceId,
@@ -45,7 +330,8 @@ impl<T: Trait> Module<T> {
 
     match &user {
       User::Account(account) => {
-        is_owner = *account == space.owner;
+        is_owner = *account == space.owner
+          || (!permission.is_owner_only() && T::Spaces::is_space_co_owner(account, space_id));
 
         // No need to check if a user is follower, if they already are an owner:
         is_follower = is_owner || T::SpaceFollows::is_space_follower(account.clone(), space_id);
@@ -136,6 +422,7 @@ impl<T: Trait> Role<T> {
 
     let mut expires_at: Option<T::BlockNumber> = None;
     if let Some(ttl) = time_to_live {
+      ensure!(ttl > Zero::zero(), Error::<T>::ZeroTimeToLive);
       expires_at = Some(ttl + <system::Module<T>>::block_number());
     }
 
@@ -166,7 +453,8 @@ impl<T: Trait> Role<T> {
   }
 
   pub fn revoke_from_users(&self, users: Vec<User<T::AccountId>>) {
-    let mut users_by_role = <UsersByRoleId<T>>::take(self.id);
+    // Otherwise the migration would grant this role again to users that are not migrated yet.
+    Module::<T>::migrate_users_of_role(self.id);
 
     for user in users.iter() {
       let role_idx_by_user_opt = Module::<T>::role_ids_by_user_in_space(&user, self.space_id).iter()
@@ -176,13 +464,8 @@ impl<T: Trait> Role<T> {
         <RoleIdsByUserInSpace<T>>::mutate(user, self.space_id, |n| { n.swap_remove(role_idx) });
       }
 
-      let user_idx_by_role_opt = users_by_role.iter().position(|x| { x == user });
-
-      if let Some(user_idx) = user_idx_by_role_opt {
-        users_by_role.swap_remove(user_idx);
-      }
+      Module::<T>::remove_user_from_role(self.id, user);
     }
-    <UsersByRoleId<T>>::insert(self.id, users_by_role);
   }
 }
 
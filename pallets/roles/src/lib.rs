@@ -5,9 +5,10 @@ use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     ensure,
     traits::Get,
+    weights::Weight,
     dispatch::DispatchResult
 };
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, traits::{One, Zero}};
 use sp_std::{collections::btree_set::BTreeSet, iter::FromIterator, prelude::*};
 use frame_system::{self as system, ensure_signed};
 
@@ -16,7 +17,7 @@ use df_traits::{
     moderation::{IsAccountBlocked, IsContentBlocked},
 };
 use pallet_permissions::{Module as Permissions, SpacePermission, SpacePermissionSet};
-use pallet_utils::{Module as Utils, Error as UtilsError, SpaceId, User, WhoAndWhen, Content};
+use pallet_utils::{Module as Utils, Error as UtilsError, BalanceOf, SpaceId, User, WhoAndWhen, Content};
 
 pub mod functions;
 pub mod rpc;
@@ -27,7 +28,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-type RoleId = u64;
+pub type RoleId = u64;
+pub type RoleTemplateId = u64;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
 pub struct Role<T: Trait> {
@@ -44,11 +46,25 @@ pub struct Role<T: Trait> {
     pub permissions: SpacePermissionSet,
 }
 
+/// A reusable set of role properties owned by an account.
+/// It can be used to create the same role in a few spaces.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
-pub struct RoleUpdate {
+pub struct RoleTemplate<T: Trait> {
+    pub created: WhoAndWhen<T>,
+    pub id: RoleTemplateId,
+    /// A default time to live of roles created from this template.
+    pub time_to_live: Option<T::BlockNumber>,
+    pub content: Content,
+    pub permissions: SpacePermissionSet,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct RoleUpdate<BlockNumber> {
     pub disabled: Option<bool>,
     pub content: Option<Content>,
     pub permissions: Option<SpacePermissionSet>,
+    /// Set a new expiration block of a role or make it permanent with `Some(None)`.
+    pub expires_at: Option<Option<BlockNumber>>,
 }
 
 /// The pallet's configuration trait.
@@ -61,6 +77,12 @@ pub trait Trait: system::Trait
 
     type MaxUsersToProcessPerDeleteRole: Get<u16>;
 
+    /// The maximum number of expired roles that can be deleted in a single block.
+    type MaxExpiredRolesToDeletePerBlock: Get<u16>;
+
+    /// The maximum number of roles that can be cloned from one space to another in a single tx.
+    type MaxRolesToClone: Get<u16>;
+
     type Spaces: SpaceForRolesProvider<AccountId=Self::AccountId>;
 
     type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
@@ -68,6 +90,8 @@ pub trait Trait: system::Trait
     type IsAccountBlocked: IsAccountBlocked<Self::AccountId>;
 
     type IsContentBlocked: IsContentBlocked;
+
+    type AfterRoleGranted: AfterRoleGranted<Self>;
 }
 
 decl_event!(
@@ -79,6 +103,12 @@ decl_event!(
         RoleDeleted(AccountId, RoleId),
         RoleGranted(AccountId, RoleId, Vec<User<AccountId>>),
         RoleRevoked(AccountId, RoleId, Vec<User<AccountId>>),
+        RoleExpired(RoleId),
+        /// All users of a deleted role have been revoked in a few steps.
+        RoleDeletionFinished(RoleId),
+        RoleTemplateCreated(AccountId, RoleTemplateId),
+        RoleTemplateDeleted(AccountId, RoleTemplateId),
+        RolesCloned(AccountId, /* from */ SpaceId, /* to */ SpaceId),
     }
 );
 
@@ -98,14 +128,37 @@ decl_error! {
         NoUsersProvided,
         /// There are too many users with this role to delete it in a single tx.
         TooManyUsersToDelete,
+        /// Role template was not found by id.
+        RoleTemplateNotFound,
+        /// RoleTemplateId counter storage overflowed.
+        RoleTemplateIdOverflow,
+        /// Only a role template owner can use or delete it.
+        NotARoleTemplateOwner,
+        /// Roles cannot be cloned to the same space they are cloned from.
+        CannotCloneRolesToSameSpace,
+        /// There are too many roles in a space to clone them in a single tx.
+        TooManyRolesToClone,
         /// Cannot disable a role that is already disabled.
         RoleAlreadyDisabled,
         /// Cannot enable a role that is already enabled.
         RoleAlreadyEnabled,
+        /// Time to live (TTL) of a role cannot be zero.
+        ZeroTimeToLive,
+        /// Role expiration block should be in the future.
+        RoleExpiryInPast,
+        /// There is no pending deletion of a role with this id.
+        RoleNotPendingDeletion,
     }
 }
 
 pub const FIRST_ROLE_ID: u64 = 1;
+pub const FIRST_ROLE_TEMPLATE_ID: u64 = 1;
+
+/// Max number of roles added to `RoleIdsByExpireBlock` by the backfill in a single block.
+pub const MAX_ROLES_TO_INDEX_BY_EXPIRE_BLOCK_PER_BLOCK: u64 = 100;
+
+/// Max number of deprecated user lists of roles moved to `UserByRoleIdAndIndex` in a single block.
+pub const MAX_ROLE_USER_LISTS_TO_MIGRATE_PER_BLOCK: usize = 100;
 
 // This pallet's storage items.
 decl_storage! {
@@ -118,9 +171,32 @@ decl_storage! {
         pub RoleById get(fn role_by_id):
             map hasher(twox_64_concat) RoleId => Option<Role<T>>;
 
-        /// A list of all users (account or space ids) that have this role.
-        pub UsersByRoleId get(fn users_by_role_id):
-            map hasher(twox_64_concat) RoleId => Vec<User<T::AccountId>>;
+        /// A deposit reserved for the `Raw` content of a role and an account it is reserved from:
+        /// the role creator or a role manager that set this content.
+        pub ContentDepositByRoleId get(fn content_deposit_by_role_id):
+            map hasher(twox_64_concat) RoleId => Option<(T::AccountId, BalanceOf<T>)>;
+
+        /// Deprecated: replaced with `UserByRoleIdAndIndex` and drained in `on_initialize`.
+        pub UsersByRoleId: map hasher(twox_64_concat) RoleId => Vec<User<T::AccountId>>;
+
+        /// The number of users (account or space ids) that have this role.
+        pub UsersCountByRoleId get(fn users_count_by_role_id):
+            map hasher(twox_64_concat) RoleId => u32;
+
+        /// Users that have this role by their index from `0` to `UsersCountByRoleId`.
+        pub UserByRoleIdAndIndex get(fn user_by_role_id_and_index): double_map
+            hasher(twox_64_concat) RoleId,
+            hasher(twox_64_concat) u32
+            => Option<User<T::AccountId>>;
+
+        /// An index of a user in `UserByRoleIdAndIndex`.
+        pub UserIndexByRoleId get(fn user_index_by_role_id): double_map
+            hasher(twox_64_concat) RoleId,
+            hasher(blake2_128_concat) User<T::AccountId>
+            => Option<u32>;
+
+        /// Whether the deprecated `UsersByRoleId` lists were fully moved to `UserByRoleIdAndIndex`.
+        pub RoleUsersMigrated get(fn role_users_migrated): bool;
 
         /// A list of all role ids available in this space.
         pub RoleIdsBySpaceId get(fn role_ids_by_space_id):
@@ -131,6 +207,35 @@ decl_storage! {
             hasher(blake2_128_concat) User<T::AccountId>,
             hasher(twox_64_concat) SpaceId
             => Vec<RoleId>;
+
+        /// Space ids of deleted roles that still have users to be revoked.
+        pub PendingRoleDeletions get(fn pending_role_deletions):
+            map hasher(twox_64_concat) RoleId => Option<SpaceId>;
+
+        /// A list of role ids that should be deleted at this block as expired.
+        pub RoleIdsByExpireBlock get(fn role_ids_by_expire_block):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<RoleId>;
+
+        /// Whether `RoleIdsByExpireBlock` has been filled with roles created before this index was added.
+        pub RoleIdsByExpireBlockIndexed get(fn role_ids_by_expire_block_indexed): bool;
+
+        /// The next role id to be added to `RoleIdsByExpireBlock` by the backfill.
+        pub NextRoleIdToIndexByExpireBlock get(fn next_role_id_to_index_by_expire_block): RoleId = FIRST_ROLE_ID;
+
+        /// The next role template id.
+        pub NextRoleTemplateId get(fn next_role_template_id): RoleTemplateId = FIRST_ROLE_TEMPLATE_ID;
+
+        /// Get role template details by its id.
+        pub RoleTemplateById get(fn role_template_by_id):
+            map hasher(twox_64_concat) RoleTemplateId => Option<RoleTemplate<T>>;
+
+        /// A deposit reserved from the template owner for the `Raw` content of a role template.
+        pub ContentDepositByRoleTemplateId get(fn content_deposit_by_role_template_id):
+            map hasher(twox_64_concat) RoleTemplateId => BalanceOf<T>;
+
+        /// A list of role template ids owned by this account.
+        pub RoleTemplateIdsByAccount get(fn role_template_ids_by_account):
+            map hasher(blake2_128_concat) T::AccountId => Vec<RoleTemplateId>;
     }
 }
 
@@ -140,17 +245,28 @@ decl_module! {
 
     const MaxUsersToProcessPerDeleteRole: u16 = T::MaxUsersToProcessPerDeleteRole::get();
 
+    const MaxExpiredRolesToDeletePerBlock: u16 = T::MaxExpiredRolesToDeletePerBlock::get();
+
+    const MaxRolesToClone: u16 = T::MaxRolesToClone::get();
+
     // Initializing errors
     type Error = Error<T>;
 
     // Initializing events
     fn deposit_event() = default;
 
+    fn on_initialize(block_number: T::BlockNumber) -> Weight {
+      Self::migrate_role_users(MAX_ROLE_USER_LISTS_TO_MIGRATE_PER_BLOCK)
+        .saturating_add(Self::index_roles_by_expire_block(block_number, MAX_ROLES_TO_INDEX_BY_EXPIRE_BLOCK_PER_BLOCK))
+        .saturating_add(Self::delete_expired_roles(block_number))
+        .saturating_add(Self::continue_any_pending_role_deletion())
+    }
+
     /// Create a new role in a space with a list of permissions.
     /// `content` points to the off-chain content with such additional info about this role
     /// as its name, description, color, etc.
     /// Only the space owner or a user with `ManageRoles` permission call this dispatch.
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 3)]
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 4)]
     pub fn create_role(
       origin,
       space_id: SpaceId,
@@ -169,28 +285,148 @@ decl_module! {
       
       let permissions_set = BTreeSet::from_iter(permissions.into_iter());
       let new_role = Role::<T>::new(who.clone(), space_id, time_to_live, content, permissions_set)?;
+      Self::insert_new_role(&new_role)?;
+
+      Self::deposit_event(RawEvent::RoleCreated(who, space_id, new_role.id));
+      Ok(())
+    }
+
+    /// Create a new role template that can be used to create the same role in a few spaces.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 4)]
+    pub fn create_role_template(
+      origin,
+      time_to_live: Option<T::BlockNumber>,
+      content: Content,
+      permissions: Vec<SpacePermission>
+    ) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      ensure!(!permissions.is_empty(), Error::<T>::NoPermissionsProvided);
+      if let Some(ttl) = time_to_live {
+        ensure!(ttl > Zero::zero(), Error::<T>::ZeroTimeToLive);
+      }
+
+      Utils::<T>::is_valid_content(content.clone())?;
+
+      let template_id = Self::next_role_template_id();
+      let next_template_id = template_id.checked_add(1).ok_or(Error::<T>::RoleTemplateIdOverflow)?;
+
+      let content_deposit = Utils::<T>::reserve_content_deposit(&who, &content)?;
+
+      let template = RoleTemplate::<T> {
+        created: WhoAndWhen::new(who.clone()),
+        id: template_id,
+        time_to_live,
+        content,
+        permissions: BTreeSet::from_iter(permissions.into_iter()),
+      };
+
+      NextRoleTemplateId::put(next_template_id);
+      <RoleTemplateById<T>>::insert(template_id, template);
+      <ContentDepositByRoleTemplateId<T>>::insert(template_id, content_deposit);
+      <RoleTemplateIdsByAccount<T>>::mutate(who.clone(), |ids| ids.push(template_id));
+
+      Self::deposit_event(RawEvent::RoleTemplateCreated(who, template_id));
+      Ok(())
+    }
+
+    /// Delete a role template. Roles created from this template are not affected.
+    /// Only the template owner can call this dispatch.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 3)]
+    pub fn delete_role_template(origin, template_id: RoleTemplateId) -> DispatchResult {
+      let who = ensure_signed(origin)?;
 
-      // TODO review strange code:
-      let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
-      NextRoleId::put(next_role_id);
+      let template = Self::require_role_template(template_id)?;
+      ensure!(template.created.account == who, Error::<T>::NotARoleTemplateOwner);
 
-      <RoleById<T>>::insert(new_role.id, new_role.clone());
-      RoleIdsBySpaceId::mutate(space_id, |role_ids| { role_ids.push(new_role.id) });
+      <RoleTemplateById<T>>::remove(template_id);
+      <RoleTemplateIdsByAccount<T>>::mutate(who.clone(), |ids| ids.retain(|id| *id != template_id));
+      Utils::<T>::unreserve_content_deposit(&who, <ContentDepositByRoleTemplateId<T>>::take(template_id));
+
+      Self::deposit_event(RawEvent::RoleTemplateDeleted(who, template_id));
+      Ok(())
+    }
+
+    /// Create a new role in a space from a role template owned by the caller.
+    /// Only the space owner or a user with `ManageRoles` permission call this dispatch.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 4)]
+    pub fn create_role_from_template(
+      origin,
+      space_id: SpaceId,
+      template_id: RoleTemplateId
+    ) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      let template = Self::require_role_template(template_id)?;
+      ensure!(template.created.account == who, Error::<T>::NotARoleTemplateOwner);
+
+      ensure!(
+        T::IsContentBlocked::is_allowed_content(template.content.clone(), space_id),
+        UtilsError::<T>::ContentIsBlocked
+      );
+
+      Self::ensure_role_manager(who.clone(), space_id)?;
+
+      let new_role = Role::<T>::new(
+        who.clone(),
+        space_id,
+        template.time_to_live,
+        template.content,
+        template.permissions
+      )?;
+      Self::insert_new_role(&new_role)?;
 
       Self::deposit_event(RawEvent::RoleCreated(who, space_id, new_role.id));
       Ok(())
     }
 
+    /// Copy all roles that are not expired from one space to another, without their users.
+    /// Only the owner of the target space or a user with `ManageRoles` permission
+    /// in the target space can call this dispatch.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(
+      3 + T::MaxRolesToClone::get() as u64,
+      1 + T::MaxRolesToClone::get() as u64 * 3
+    )]
+    pub fn clone_roles(origin, from_space: SpaceId, to_space: SpaceId) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      ensure!(from_space != to_space, Error::<T>::CannotCloneRolesToSameSpace);
+
+      let role_ids = Self::role_ids_by_space_id(from_space);
+      ensure!(role_ids.len() <= T::MaxRolesToClone::get() as usize, Error::<T>::TooManyRolesToClone);
+
+      Self::ensure_role_manager(who.clone(), to_space)?;
+
+      let now = <system::Module<T>>::block_number();
+      for role in role_ids.iter().filter_map(Self::role_by_id) {
+        let is_expired = role.expires_at.map_or(false, |expires_at| expires_at <= now);
+        if is_expired || !T::IsContentBlocked::is_allowed_content(role.content.clone(), to_space) {
+          continue;
+        }
+
+        let mut new_role = Role::<T>::new(who.clone(), to_space, None, role.content, role.permissions)?;
+        new_role.disabled = role.disabled;
+        new_role.expires_at = role.expires_at;
+        Self::insert_new_role(&new_role)?;
+
+        Self::deposit_event(RawEvent::RoleCreated(who.clone(), to_space, new_role.id));
+      }
+
+      Self::deposit_event(RawEvent::RolesCloned(who, from_space, to_space));
+      Ok(())
+    }
+
     /// Update an existing role by its id.
     /// Only the space owner or a user with `ManageRoles` permission call this dispatch.
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
-    pub fn update_role(origin, role_id: RoleId, update: RoleUpdate) -> DispatchResult {
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
+    pub fn update_role(origin, role_id: RoleId, update: RoleUpdate<T::BlockNumber>) -> DispatchResult {
       let who = ensure_signed(origin)?;
 
       let has_updates =
         update.disabled.is_some() ||
         update.content.is_some() ||
-        update.permissions.is_some();
+        update.permissions.is_some() ||
+        update.expires_at.is_some();
 
       ensure!(has_updates, Error::<T>::NoUpdatesProvided);
 
@@ -199,6 +435,7 @@ decl_module! {
       Self::ensure_role_manager(who.clone(), role.space_id)?;
 
       let mut is_update_applied = false;
+      let mut is_content_updated = false;
 
       if let Some(disabled) = update.disabled {
         if disabled != role.disabled {
@@ -211,8 +448,10 @@ decl_module! {
         if content != role.content {
           Utils::<T>::is_valid_content(content.clone())?;
           ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), role.space_id), UtilsError::<T>::ContentIsBlocked);
+          Utils::<T>::ensure_can_reserve_content_deposit(&who, &content)?;
 
           role.content = content;
+          is_content_updated = true;
           is_update_applied = true;
         }
       }
@@ -228,9 +467,34 @@ decl_module! {
         }
       }
 
+      let old_expires_at = role.expires_at;
+      if let Some(expires_at) = update.expires_at {
+        if expires_at != role.expires_at {
+          if let Some(new_expires_at) = expires_at {
+            ensure!(
+              new_expires_at > <system::Module<T>>::block_number(),
+              Error::<T>::RoleExpiryInPast
+            );
+          }
+
+          role.expires_at = expires_at;
+          is_update_applied = true;
+        }
+      }
+
       if is_update_applied {
+        if is_content_updated {
+          let old_deposit = Self::content_deposit_by_role_id(role_id);
+          let new_deposit = Utils::<T>::replace_content_deposit_of_depositor(&who, old_deposit, &role.content)?;
+          <ContentDepositByRoleId<T>>::insert(role_id, new_deposit);
+        }
+
         role.updated = Some(WhoAndWhen::<T>::new(who.clone()));
 
+        if old_expires_at != role.expires_at {
+          Self::move_role_in_expiry_index(role_id, old_expires_at, role.expires_at);
+        }
+
         <RoleById<T>>::insert(role_id, role);
         Self::deposit_event(RawEvent::RoleUpdated(who, role_id));
       }
@@ -238,8 +502,14 @@ decl_module! {
     }
 
     /// Delete a role from all associated storage items.
+    /// The role stops granting permissions at once, but if it has more than
+    /// `MaxUsersToProcessPerDeleteRole` users, the rest of them will be revoked
+    /// by `continue_delete_role` or automatically in the next blocks.
     /// Only the space owner or a user with `ManageRoles` permission call this dispatch.
-    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(6, 5)]
+    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(
+      7 + T::MaxUsersToProcessPerDeleteRole::get() as u64,
+      6 + T::MaxUsersToProcessPerDeleteRole::get() as u64
+    )]
     pub fn delete_role(origin, role_id: RoleId) -> DispatchResult {
       let who = ensure_signed(origin)?;
 
@@ -247,31 +517,33 @@ decl_module! {
 
       Self::ensure_role_manager(who.clone(), role.space_id)?;
 
-      let users = Self::users_by_role_id(role_id);
-      ensure!(
-        users.len() <= T::MaxUsersToProcessPerDeleteRole::get() as usize,
-        Error::<T>::TooManyUsersToDelete
-      );
-
-      let role_idx_by_space_opt = Self::role_ids_by_space_id(role.space_id).iter()
-        .position(|x| { *x == role_id });
-
-      if let Some(role_idx) = role_idx_by_space_opt {
-        RoleIdsBySpaceId::mutate(role.space_id, |n| { n.swap_remove(role_idx) });
-      }
-
-      role.revoke_from_users(users);
-
-      <RoleById<T>>::remove(role_id);
-      <UsersByRoleId<T>>::remove(role_id);
+      Self::delete_role_from_storage(&role);
 
       Self::deposit_event(RawEvent::RoleDeleted(who, role_id));
       Ok(())
     }
 
+    /// Revoke the next chunk of users from a role which deletion is pending.
+    /// Anyone can call this dispatch to speed up cleaning of the storage.
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(
+      2 + T::MaxUsersToProcessPerDeleteRole::get() as u64,
+      2 + T::MaxUsersToProcessPerDeleteRole::get() as u64
+    )]
+    pub fn continue_delete_role(origin, role_id: RoleId) -> DispatchResult {
+      ensure_signed(origin)?;
+
+      let space_id = Self::pending_role_deletions(role_id).ok_or(Error::<T>::RoleNotPendingDeletion)?;
+      Self::revoke_next_users_of_deleted_role(role_id, space_id);
+      Ok(())
+    }
+
     /// Grant a role to a list of users.
     /// Only the space owner or a user with `ManageRoles` permission call this dispatch.
-    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(4, 2)]
+    // Each user is added to the role index and to the roles of a user in space, then notified.
+    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(
+      4 + 4 * users.len() as u64,
+      2 + 6 * users.len() as u64
+    )]
     pub fn grant_role(origin, role_id: RoleId, users: Vec<User<T::AccountId>>) -> DispatchResult {
       let who = ensure_signed(origin)?;
 
@@ -282,15 +554,18 @@ decl_module! {
 
       Self::ensure_role_manager(who.clone(), role.space_id)?;
 
+      let mut new_users: Vec<User<T::AccountId>> = Vec::new();
       for user in users_set.iter() {
-        if !Self::users_by_role_id(role_id).contains(&user) {
-          <UsersByRoleId<T>>::mutate(role_id, |users| { users.push(user.clone()); });
+        if Self::add_user_to_role(role_id, user) {
+          new_users.push(user.clone());
         }
         if !Self::role_ids_by_user_in_space(user.clone(), role.space_id).contains(&role_id) {
           <RoleIdsByUserInSpace<T>>::mutate(user.clone(), role.space_id, |roles| { roles.push(role_id); })
         }
       }
 
+      T::AfterRoleGranted::after_role_granted(&who, &role, &new_users);
+
       Self::deposit_event(RawEvent::RoleGranted(who, role_id, users_set.iter().cloned().collect()));
       Ok(())
     }
@@ -314,3 +589,9 @@ decl_module! {
     }
   }
 }
+
+/// Handler that will be called after a role is granted to users that did not have it before.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait AfterRoleGranted<T: Trait> {
+    fn after_role_granted(granted_by: &T::AccountId, role: &Role<T>, users: &[User<T::AccountId>]);
+}
@@ -94,6 +94,8 @@ impl pallet_balances::Trait for Test {
 parameter_types! {
     pub const MinHandleLen: u32 = 5;
     pub const MaxHandleLen: u32 = 50;
+    pub const MaxRawContentLen: u32 = 64;
+    pub const RawContentDepositPerByte: u64 = 0;
 }
 
 impl pallet_utils::Trait for Test {
@@ -101,6 +103,9 @@ impl pallet_utils::Trait for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type MaxRawContentLen = MaxRawContentLen;
+    type RawContentDepositPerByte = RawContentDepositPerByte;
+    type ContentValidator = pallet_utils::DefaultContentValidator<Test>;
 }
 
 use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -111,15 +116,20 @@ impl pallet_permissions::Trait for Test {
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 20;
+  pub const MaxExpiredRolesToDeletePerBlock: u16 = 2;
+  pub const MaxRolesToClone: u16 = 3;
 }
 
 impl Trait for Test {
     type Event = ();
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type MaxExpiredRolesToDeletePerBlock = MaxExpiredRolesToDeletePerBlock;
+    type MaxRolesToClone = MaxRolesToClone;
     type Spaces = Roles;
     type SpaceFollows = Roles;
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
+    type AfterRoleGranted = ();
 }
 
 type System = system::Module<Test>;
@@ -207,6 +217,7 @@ pub(crate) const ROLE4: RoleId = 4;
 
 pub(crate) const SPACE1: SpaceId = 1;
 pub(crate) const SPACE2: SpaceId = 2;
+pub(crate) const SPACE3: SpaceId = 3;
 
 pub(crate) fn default_role_content_ipfs() -> Content {
     Content::IPFS(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec())
@@ -236,7 +247,7 @@ pub(crate) fn permission_set_random() -> Vec<SpacePermission> {
 }
 
 pub(crate) fn valid_space_ids() -> Vec<SpaceId> {
-    vec![SPACE1]
+    vec![SPACE1, SPACE3]
 }
 
 /// Permissions Set that includes nothing
@@ -244,11 +255,21 @@ pub(crate) fn permission_set_empty() -> Vec<SpacePermission> {
     vec![]
 }
 
-pub(crate) fn role_update(disabled: Option<bool>, content: Option<Content>, permissions: Option<BTreeSet<SpacePermission>>) -> RoleUpdate {
+pub(crate) fn role_update(disabled: Option<bool>, content: Option<Content>, permissions: Option<BTreeSet<SpacePermission>>) -> RoleUpdate<BlockNumber> {
     RoleUpdate {
         disabled,
         content,
         permissions,
+        expires_at: None,
+    }
+}
+
+pub(crate) fn role_expiry_update(expires_at: Option<BlockNumber>) -> RoleUpdate<BlockNumber> {
+    RoleUpdate {
+        disabled: None,
+        content: None,
+        permissions: None,
+        expires_at: Some(expires_at),
     }
 }
 
@@ -280,7 +301,7 @@ pub(crate) fn _update_default_role() -> DispatchResult {
 pub(crate) fn _update_role(
     origin: Option<Origin>,
     role_id: Option<RoleId>,
-    update: Option<RoleUpdate>
+    update: Option<RoleUpdate<BlockNumber>>
 ) -> DispatchResult {
     Roles::update_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
@@ -339,4 +360,44 @@ pub(crate) fn _delete_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         role_id.unwrap_or(ROLE1)
     )
-}
\ No newline at end of file
+}
+
+pub(crate) fn _continue_delete_role(
+    origin: Option<Origin>,
+    role_id: Option<RoleId>
+) -> DispatchResult {
+    Roles::continue_delete_role(
+        origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+        role_id.unwrap_or(ROLE1)
+    )
+}
+
+pub(crate) const ROLE_TEMPLATE1: u64 = 1;
+
+pub(crate) fn _create_default_role_template() -> DispatchResult {
+    Roles::create_role_template(
+        Origin::signed(ACCOUNT1),
+        Some(10),
+        self::default_role_content_ipfs(),
+        self::permission_set_default(),
+    )
+}
+
+pub(crate) fn _create_role_from_template(
+    origin: Option<Origin>,
+    space_id: Option<SpaceId>,
+) -> DispatchResult {
+    Roles::create_role_from_template(
+        origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        ROLE_TEMPLATE1,
+    )
+}
+
+pub(crate) fn _clone_roles(origin: Option<Origin>) -> DispatchResult {
+    Roles::clone_roles(
+        origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+        SPACE1,
+        SPACE3,
+    )
+}
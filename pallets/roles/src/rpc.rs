@@ -1,12 +1,56 @@
-use crate::{Module, Trait, Role, RoleIdsByUserInSpace};
-
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use frame_support::storage::IterableStorageDoubleMap;
 use sp_std::prelude::*;
 use sp_std::collections::{ btree_set::BTreeSet };
 
-use pallet_utils::{SpaceId, User};
+use pallet_utils::{bool_to_option, SpaceId, User, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}};
 use pallet_permissions::{SpacePermission};
 
+use crate::{Module, Trait, Role, RoleId, RoleIdsByUserInSpace};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatRole<AccountId, BlockNumber> {
+    pub id: RoleId,
+
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub who_and_when: FlatWhoAndWhen<AccountId, BlockNumber>,
+
+    pub space_id: SpaceId,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub is_disabled: Option<bool>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub expires_at: Option<BlockNumber>,
+
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub content: FlatContent,
+
+    pub permissions: Vec<SpacePermission>,
+}
+
+impl<T: Trait> From<Role<T>> for FlatRole<T::AccountId, T::BlockNumber> {
+    fn from(from: Role<T>) -> Self {
+        let Role {
+            created, updated, id, space_id, disabled, expires_at, content, permissions
+        } = from;
+
+        Self {
+            id,
+            who_and_when: (created, updated).into(),
+            space_id,
+            is_disabled: bool_to_option(disabled),
+            expires_at,
+            content: content.into(),
+            permissions: permissions.into_iter().collect(),
+        }
+    }
+}
+
 impl<T: Trait> Module<T> {
     pub fn get_space_permissions_by_account(
         account: T::AccountId,
@@ -25,7 +69,7 @@ impl<T: Trait> Module<T> {
 
         Self::role_ids_by_space_id(space_id)
             .iter()
-            .flat_map(Self::users_by_role_id)
+            .flat_map(|role_id| Self::users_by_role_id(*role_id))
             .filter_map(|user| user.maybe_account())
             .collect::<BTreeSet<_>>()
             .iter().cloned().collect()
@@ -38,4 +82,42 @@ impl<T: Trait> Module<T> {
             .map(|(space_id, _)| space_id)
             .collect()
     }
-}
\ No newline at end of file
+
+    pub fn get_roles_by_space_id(
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16
+    ) -> Vec<FlatRole<T::AccountId, T::BlockNumber>> {
+
+        Self::role_ids_by_space_id(space_id)
+            .iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .filter_map(Self::role_by_id)
+            .map(|role| role.into())
+            .collect()
+    }
+
+    /// Get users of a role page by page. Users can be both accounts and spaces.
+    pub fn get_users_by_role_id(role_id: RoleId, offset: u64, limit: u16) -> Vec<User<T::AccountId>> {
+        let users_count = Self::users_count_by_role_id(role_id) as u64;
+        let start = offset.min(users_count);
+        let end = start.saturating_add(limit as u64).min(users_count);
+
+        (start..end)
+            .filter_map(|index| Self::user_by_role_id_and_index(role_id, index as u32))
+            .collect()
+    }
+
+    pub fn get_roles_by_user_in_space(
+        user: User<T::AccountId>,
+        space_id: SpaceId
+    ) -> Vec<FlatRole<T::AccountId, T::BlockNumber>> {
+
+        Self::role_ids_by_user_in_space(user, space_id)
+            .iter()
+            .filter_map(Self::role_by_id)
+            .map(|role| role.into())
+            .collect()
+    }
+}
@@ -3,6 +3,7 @@ use crate::*;
 
 use frame_support::{
     assert_ok, assert_noop,
+    traits::OnInitialize,
 };
 use pallet_utils::{Error as UtilsError};
 
@@ -565,16 +566,125 @@ fn delete_role_should_fail_with_no_permission() {
 }
 
 #[test]
-fn delete_role_should_fail_with_too_many_users_for_delete_role() {
+fn revoke_role_should_move_last_user_to_index_of_revoked_user() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role(None, None, Some(vec![
+            User::Account(ACCOUNT2), User::Account(ACCOUNT3), User::Space(SPACE2)
+        ])));
+
+        assert_ok!(_revoke_default_role());
+
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![User::Space(SPACE2), User::Account(ACCOUNT3)]);
+        assert_eq!(Roles::user_index_by_role_id(ROLE1, User::Space(SPACE2)), Some(0));
+        assert!(Roles::user_index_by_role_id(ROLE1, User::Account(ACCOUNT2)).is_none());
+        assert_eq!(Roles::users_count_by_role_id(ROLE1), 2);
+    });
+}
+
+#[test]
+fn migrate_role_users_should_move_users_lists_in_batches() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_create_default_role()); // RoleId 2
+
+        // Simulate user lists stored before roles had indexed users.
+        UsersByRoleId::<Test>::insert(ROLE1, vec![User::Account(ACCOUNT2), User::Account(ACCOUNT3)]);
+        UsersByRoleId::<Test>::insert(ROLE2, vec![User::Account(ACCOUNT3)]);
+
+        Roles::migrate_role_users(1);
+        assert!(!Roles::role_users_migrated());
+
+        Roles::migrate_role_users(1);
+        assert!(!Roles::role_users_migrated());
+
+        Roles::migrate_role_users(1);
+        assert!(Roles::role_users_migrated());
+
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![User::Account(ACCOUNT2), User::Account(ACCOUNT3)]);
+        assert_eq!(Roles::users_by_role_id(ROLE2), vec![User::Account(ACCOUNT3)]);
+        assert!(!UsersByRoleId::<Test>::contains_key(ROLE1));
+        assert!(!UsersByRoleId::<Test>::contains_key(ROLE2));
+    });
+}
+
+#[test]
+fn delete_role_should_revoke_users_that_are_not_migrated_yet() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+
+        // Simulate a user list stored before roles had indexed users.
+        UsersByRoleId::<Test>::insert(ROLE1, vec![User::Account(ACCOUNT2)]);
+        RoleIdsByUserInSpace::<Test>::insert(User::Account(ACCOUNT2), SPACE1, vec![ROLE1]);
+
+        assert_ok!(_delete_default_role());
+
+        assert!(!UsersByRoleId::<Test>::contains_key(ROLE1));
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn revoke_role_should_not_be_undone_by_migration_of_role_users() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+
+        // Simulate a user list stored before roles had indexed users.
+        UsersByRoleId::<Test>::insert(ROLE1, vec![User::Account(ACCOUNT2), User::Account(ACCOUNT3)]);
+        RoleIdsByUserInSpace::<Test>::insert(User::Account(ACCOUNT2), SPACE1, vec![ROLE1]);
+        RoleIdsByUserInSpace::<Test>::insert(User::Account(ACCOUNT3), SPACE1, vec![ROLE1]);
+
+        assert_ok!(_revoke_role(None, None, Some(vec![User::Account(ACCOUNT2)])));
+
+        Roles::migrate_role_users(10);
+        assert!(Roles::role_users_migrated());
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![User::Account(ACCOUNT3)]);
+        assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn delete_role_should_revoke_too_many_users_in_a_few_steps() {
     ExtBuilder::build().execute_with(|| {
         let mut users: Vec<User<AccountId>> = Vec::new();
-        for account in 2..23 {
+        for account in 2..47 {
             users.push(User::Account(account));
         }
 
         assert_ok!(_create_default_role()); // RoleId 1
-        assert_ok!(_grant_role(None, None, Some(users))); // Grant RoleId 1 to ACCOUNT2-ACCOUNT20
-        assert_noop!(_delete_default_role(), Error::<Test>::TooManyUsersToDelete);
+        assert_ok!(_grant_role(None, None, Some(users.clone()))); // Grant RoleId 1 to ACCOUNT2-ACCOUNT46
+        assert_ok!(_delete_default_role());
+
+        // The role is deleted at once, but only the first 20 users are revoked
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+        assert_eq!(Roles::users_by_role_id(ROLE1).len(), 25);
+        assert_eq!(Roles::pending_role_deletions(ROLE1), Some(SPACE1));
+
+        assert_ok!(_continue_delete_role(None, None));
+        assert_eq!(Roles::users_by_role_id(ROLE1).len(), 5);
+
+        // The rest of users should be revoked automatically
+        Roles::on_initialize(2);
+
+        assert!(Roles::pending_role_deletions(ROLE1).is_none());
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+        for user in users {
+            assert!(Roles::role_ids_by_user_in_space(user, SPACE1).is_empty());
+        }
+    });
+}
+
+#[test]
+fn continue_delete_role_should_fail_when_role_is_not_pending_deletion() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_default_role());
+        assert_ok!(_delete_default_role());
+
+        assert!(Roles::pending_role_deletions(ROLE1).is_none());
+        assert_noop!(_continue_delete_role(None, None), Error::<Test>::RoleNotPendingDeletion);
     });
 }
 
@@ -590,3 +700,249 @@ fn delete_role_should_fail_with_a_few_roles_no_permission() {
         );
     });
 }
+
+fn _create_role_expiring_in(time_to_live: BlockNumber) -> DispatchResult {
+    _create_role(None, None, Some(Some(time_to_live)), None, None)
+}
+
+#[test]
+fn create_role_should_fail_with_zero_time_to_live() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_create_role_expiring_in(0), Error::<Test>::ZeroTimeToLive);
+    });
+}
+
+#[test]
+fn expired_role_should_be_deleted_on_initialize() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1 expires at block 6
+        assert_ok!(_grant_default_role());
+        assert_eq!(Roles::role_ids_by_expire_block(6), vec![ROLE1]);
+
+        Roles::on_initialize(6);
+
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+        assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+        assert!(Roles::role_ids_by_expire_block(6).is_empty());
+    });
+}
+
+#[test]
+fn expired_roles_over_the_limit_should_be_deleted_in_the_next_block() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 2
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 3
+
+        Roles::on_initialize(6);
+
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::role_by_id(ROLE2).is_none());
+        assert!(Roles::role_by_id(ROLE3).is_some());
+        assert_eq!(Roles::role_ids_by_expire_block(7), vec![ROLE3]);
+
+        Roles::on_initialize(7);
+
+        assert!(Roles::role_by_id(ROLE3).is_none());
+        assert!(Roles::role_ids_by_expire_block(7).is_empty());
+    });
+}
+
+#[test]
+fn index_roles_by_expire_block_should_backfill_roles_in_batches() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1 expires at block 6
+        assert_ok!(_create_default_role()); // RoleId 2 never expires
+        assert_ok!(_create_role_expiring_in(2)); // RoleId 3 expires at block 3
+
+        // Simulate roles created before the expiry index was added.
+        RoleIdsByExpireBlock::<Test>::remove(6);
+        RoleIdsByExpireBlock::<Test>::remove(3);
+
+        Roles::index_roles_by_expire_block(4, 2);
+        assert_eq!(Roles::role_ids_by_expire_block(6), vec![ROLE1]);
+        assert!(!Roles::role_ids_by_expire_block_indexed());
+        assert_eq!(Roles::next_role_id_to_index_by_expire_block(), ROLE3);
+
+        // An already expired role should be deleted at the current block.
+        Roles::index_roles_by_expire_block(4, 2);
+        assert_eq!(Roles::role_ids_by_expire_block(4), vec![ROLE3]);
+        assert!(Roles::role_ids_by_expire_block_indexed());
+
+        Roles::on_initialize(6);
+        assert!(Roles::role_ids_by_expire_block(6).is_empty());
+        assert!(Roles::role_by_id(ROLE1).is_none());
+    });
+}
+
+#[test]
+fn index_roles_by_expire_block_should_skip_already_indexed_roles() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1 expires at block 6
+
+        Roles::on_initialize(2);
+
+        assert_eq!(Roles::role_ids_by_expire_block(6), vec![ROLE1]);
+        assert!(Roles::role_ids_by_expire_block_indexed());
+    });
+}
+
+#[test]
+fn update_role_should_renew_and_clear_role_expiry() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1 expires at block 6
+
+        assert_ok!(_update_role(None, None, Some(self::role_expiry_update(Some(10)))));
+        assert_eq!(Roles::role_by_id(ROLE1).unwrap().expires_at, Some(10));
+        assert!(Roles::role_ids_by_expire_block(6).is_empty());
+        assert_eq!(Roles::role_ids_by_expire_block(10), vec![ROLE1]);
+
+        Roles::on_initialize(6);
+        assert!(Roles::role_by_id(ROLE1).is_some());
+
+        assert_ok!(_update_role(None, None, Some(self::role_expiry_update(None))));
+        assert_eq!(Roles::role_by_id(ROLE1).unwrap().expires_at, None);
+        assert!(Roles::role_ids_by_expire_block(10).is_empty());
+    });
+}
+
+#[test]
+fn update_role_should_fail_with_expiry_in_past() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+
+        assert_noop!(
+            _update_role(None, None, Some(self::role_expiry_update(Some(1)))),
+            Error::<Test>::RoleExpiryInPast
+        );
+    });
+}
+
+#[test]
+fn delete_role_should_remove_role_from_expiry_index() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role_expiring_in(5)); // RoleId 1 expires at block 6
+        assert_ok!(_delete_default_role());
+
+        assert!(Roles::role_ids_by_expire_block(6).is_empty());
+    });
+}
+
+#[test]
+fn create_role_from_template_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role_template());
+        assert_eq!(Roles::role_template_ids_by_account(ACCOUNT1), vec![ROLE_TEMPLATE1]);
+
+        assert_ok!(_create_role_from_template(None, None)); // RoleId 1
+        assert_ok!(_create_role_from_template(None, Some(SPACE3))); // RoleId 2
+
+        let role = Roles::role_by_id(ROLE1).unwrap();
+        assert_eq!(role.space_id, SPACE1);
+        assert_eq!(role.expires_at, Some(11));
+        assert_eq!(role.content, self::default_role_content_ipfs());
+        assert_eq!(
+            role.permissions,
+            BTreeSet::from_iter(self::permission_set_default().into_iter())
+        );
+        assert_eq!(Roles::role_by_id(ROLE2).unwrap().space_id, SPACE3);
+    });
+}
+
+#[test]
+fn create_role_from_template_should_fail_when_account_is_not_template_owner() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role_template());
+
+        assert_noop!(
+            _create_role_from_template(Some(Origin::signed(ACCOUNT2)), None),
+            Error::<Test>::NotARoleTemplateOwner
+        );
+    });
+}
+
+#[test]
+fn delete_role_template_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role_template());
+        assert_ok!(Roles::delete_role_template(Origin::signed(ACCOUNT1), ROLE_TEMPLATE1));
+
+        assert!(Roles::role_template_by_id(ROLE_TEMPLATE1).is_none());
+        assert!(Roles::role_template_ids_by_account(ACCOUNT1).is_empty());
+        assert_noop!(_create_role_from_template(None, None), Error::<Test>::RoleTemplateNotFound);
+    });
+}
+
+#[test]
+fn clone_roles_should_copy_roles_without_users() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
+        assert_ok!(_clone_roles(None)); // RoleId 3 and RoleId 4
+
+        assert_eq!(Roles::role_ids_by_space_id(SPACE3), vec![ROLE3, ROLE4]);
+        assert!(Roles::users_by_role_id(ROLE3).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE3).is_empty());
+
+        let original_role = Roles::role_by_id(ROLE1).unwrap();
+        let cloned_role = Roles::role_by_id(ROLE3).unwrap();
+        assert_eq!(cloned_role.space_id, SPACE3);
+        assert_eq!(cloned_role.permissions, original_role.permissions);
+        assert_eq!(cloned_role.content, original_role.content);
+    });
+}
+
+#[test]
+fn clone_roles_should_fail_with_no_permission() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
+        assert_noop!(
+            _clone_roles(Some(Origin::signed(ACCOUNT3))),
+            Error::<Test>::NoPermissionToManageRoles
+        );
+    });
+}
+
+#[test]
+fn clone_roles_should_fail_with_too_many_roles() {
+    ExtBuilder::build().execute_with(|| {
+        for _ in 0..4 {
+            assert_ok!(_create_default_role());
+        }
+
+        assert_noop!(_clone_roles(None), Error::<Test>::TooManyRolesToClone);
+    });
+}
+
+#[test]
+fn get_users_by_role_id_should_return_accounts_and_spaces_by_pages() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role(None, None, Some(vec![
+            User::Account(ACCOUNT2),
+            User::Account(ACCOUNT3),
+            User::Space(SPACE2),
+        ])));
+
+        assert_eq!(
+            Roles::get_users_by_role_id(ROLE1, 0, 2),
+            vec![User::Account(ACCOUNT2), User::Account(ACCOUNT3)]
+        );
+        assert_eq!(Roles::get_users_by_role_id(ROLE1, 2, 2), vec![User::Space(SPACE2)]);
+
+        let roles = Roles::get_roles_by_user_in_space(User::Space(SPACE2), SPACE1);
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].id, ROLE1);
+    });
+}
+
+#[test]
+fn get_roles_by_space_id_should_return_roles_by_pages() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2().execute_with(|| {
+        let roles = Roles::get_roles_by_space_id(SPACE1, 1, 10);
+
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].id, ROLE2);
+        assert_eq!(roles[0].permissions, self::permission_set_default());
+        assert_eq!(roles[0].is_disabled, None);
+    });
+}
@@ -1,17 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult, ensure, traits::Get,
+    storage::{IterableStorageDoubleMap, IterableStorageMap}, weights::Weight,
 };
 use sp_runtime::RuntimeDebug;
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
 use sp_std::prelude::*;
-use frame_system::{self as system};
+use frame_system::{self as system, ensure_root};
 
 use pallet_posts::{PostScores, Post, PostById, PostExtension};
 use pallet_profile_follows::{BeforeAccountFollowed, BeforeAccountUnfollowed};
-use pallet_profiles::{Module as Profiles, SocialAccountById};
+use pallet_profiles::{Module as Profiles, SocialAccount, SocialAccountById};
 use pallet_reactions::{PostReactionScores, ReactionKind};
 use pallet_space_follows::{BeforeSpaceFollowed, BeforeSpaceUnfollowed};
 use pallet_spaces::{Space, SpaceById};
@@ -36,6 +40,62 @@ impl Default for ScoringAction {
     }
 }
 
+/// Weights of the social actions that are used to calculate score and reputation diffs.
+/// All weights are zero by default, the actual ones are set in the genesis config.
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ScoringWeights {
+    pub follow_space: i16,
+    pub follow_account: i16,
+
+    pub share_post: i16,
+    pub upvote_post: i16,
+    pub downvote_post: i16,
+
+    pub create_comment: i16,
+    pub share_comment: i16,
+    pub upvote_comment: i16,
+    pub downvote_comment: i16,
+}
+
+impl ScoringWeights {
+    /// Weights that were hard-coded in the pallet before they became a part of the genesis config.
+    /// Written to `ActionWeights` on a runtime upgrade if a chain has no weights stored yet.
+    pub fn pre_genesis_config_weights() -> Self {
+        ScoringWeights {
+            follow_space: 7,
+            follow_account: 3,
+
+            share_post: 7,
+            upvote_post: 5,
+            downvote_post: -3,
+
+            create_comment: 5,
+            share_comment: 5,
+            upvote_comment: 4,
+            downvote_comment: -2,
+        }
+    }
+
+    pub fn weight_of(&self, action: ScoringAction) -> i16 {
+        use ScoringAction::*;
+        match action {
+            UpvotePost => self.upvote_post,
+            DownvotePost => self.downvote_post,
+            SharePost => self.share_post,
+            CreateComment => self.create_comment,
+            UpvoteComment => self.upvote_comment,
+            DownvoteComment => self.downvote_comment,
+            ShareComment => self.share_comment,
+            FollowSpace => self.follow_space,
+            FollowAccount => self.follow_account,
+        }
+    }
+}
+
+/// Max number of deprecated reputation diffs moved to `ReputationDiffByActor` in a single block.
+pub const MAX_REPUTATION_DIFFS_TO_MIGRATE_PER_BLOCK: usize = 100;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
@@ -49,18 +109,9 @@ pub trait Trait: system::Trait
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    // Weights of the social actions
-    type FollowSpaceActionWeight: Get<i16>;
-    type FollowAccountActionWeight: Get<i16>;
-
-    type SharePostActionWeight: Get<i16>;
-    type UpvotePostActionWeight: Get<i16>;
-    type DownvotePostActionWeight: Get<i16>;
-
-    type CreateCommentActionWeight: Get<i16>;
-    type ShareCommentActionWeight: Get<i16>;
-    type UpvoteCommentActionWeight: Get<i16>;
-    type DownvoteCommentActionWeight: Get<i16>;
+    /// The number of blocks after which the part of account reputation above the minimum (1) halves.
+    /// Zero disables the reputation decay.
+    type ReputationHalfLife: Get<Self::BlockNumber>;
 }
 
 decl_error! {
@@ -71,6 +122,12 @@ decl_error! {
         NotRootPost,
         /// Post extension is not a comment.
         NotComment,
+        /// New scoring weights are the same as the current ones.
+        NoUpdatesForActionWeights,
+        /// Provided number of reputation diffs is lower than the number of diffs received by an account.
+        TooLowReputationDiffsCount,
+        /// Reputation diffs made before `ReputationDiffBySubject` was added are not migrated yet.
+        ReputationDiffsNotMigrated,
     }
 }
 
@@ -78,12 +135,42 @@ decl_error! {
 decl_storage! {
     trait Store for Module<T: Trait> as ScoresModule {
 
-        // TODO shorten name? (refactor)
-        pub AccountReputationDiffByAccount get(fn account_reputation_diff_by_account):
+        /// Deprecated: reputation diffs that are not counted in `ReputationDiffBySubject`.
+        /// Replaced with `ReputationDiffByActor` and drained in `on_initialize`.
+        pub AccountReputationDiffByAccount:
+            map hasher(blake2_128_concat) (/* actor */ T::AccountId, /* subject */ T::AccountId, ScoringAction) => Option<i16>;
+
+        pub ReputationDiffByActor:
             map hasher(blake2_128_concat) (/* actor */ T::AccountId, /* subject */ T::AccountId, ScoringAction) => Option<i16>;
 
+        /// Whether the deprecated `AccountReputationDiffByAccount` diffs were fully moved to `ReputationDiffByActor`.
+        pub ReputationDiffsMigrated get(fn reputation_diffs_migrated): bool;
+
         pub PostScoreByAccount get(fn post_score_by_account):
             map hasher(blake2_128_concat) (/* actor */ T::AccountId, /* subject */ PostId, ScoringAction) => Option<i16>;
+
+        /// Weights of the social actions. Changing them does not affect scores that were already awarded.
+        pub ActionWeights get(fn action_weights) config(): ScoringWeights;
+
+        /// The sum of reputation changes an account (subject) has received from an actor by an action.
+        pub ReputationDiffBySubject get(fn reputation_diff_by_subject):
+            double_map
+                hasher(blake2_128_concat) /* subject */ T::AccountId,
+                hasher(blake2_128_concat) (/* actor */ T::AccountId, ScoringAction)
+            => i32;
+
+        /// The number of entries in `ReputationDiffBySubject` of an account.
+        pub ReputationDiffsCountBySubject get(fn reputation_diffs_count_by_subject):
+            map hasher(blake2_128_concat) T::AccountId => u32;
+
+        /// A block number until which the reputation decay was already applied to an account.
+        /// The reputation of an account without this record starts to decay from its next change.
+        pub LastReputationDecayAt get(fn last_reputation_decay_at):
+            map hasher(blake2_128_concat) T::AccountId => T::BlockNumber;
+    }
+    add_extra_genesis {
+        // A new chain has no reputation diffs to migrate.
+        build(|_| ReputationDiffsMigrated::put(true));
     }
 }
 
@@ -92,6 +179,8 @@ decl_event!(
         <T as system::Trait>::AccountId,
     {
         AccountReputationChanged(AccountId, ScoringAction, u32),
+        ActionWeightsUpdated(ScoringWeights),
+        ReputationRecalculated(AccountId, u32),
     }
 );
 
@@ -99,27 +188,113 @@ decl_event!(
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 
-        /// Weights of the related social account actions
-        const FollowSpaceActionWeight: i16 = T::FollowSpaceActionWeight::get();
-        const FollowAccountActionWeight: i16 = T::FollowAccountActionWeight::get();
-        const UpvotePostActionWeight: i16 = T::UpvotePostActionWeight::get();
-        const DownvotePostActionWeight: i16 = T::DownvotePostActionWeight::get();
-        const SharePostActionWeight: i16 = T::SharePostActionWeight::get();
-        const CreateCommentActionWeight: i16 = T::CreateCommentActionWeight::get();
-        const UpvoteCommentActionWeight: i16 = T::UpvoteCommentActionWeight::get();
-        const DownvoteCommentActionWeight: i16 = T::DownvoteCommentActionWeight::get();
-        const ShareCommentActionWeight: i16 = T::ShareCommentActionWeight::get();
+        /// The number of blocks after which the part of account reputation above the minimum halves.
+        const ReputationHalfLife: T::BlockNumber = T::ReputationHalfLife::get();
 
         // Initializing errors
         type Error = Error<T>;
 
         // Initializing events
         fn deposit_event() = default;
+
+        fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+            Self::migrate_reputation_diffs(MAX_REPUTATION_DIFFS_TO_MIGRATE_PER_BLOCK)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if ActionWeights::exists() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            ActionWeights::put(ScoringWeights::pre_genesis_config_weights());
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        /// Replace weights of the social actions. New weights will be used only for new actions.
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
+        pub fn set_action_weights(origin, weights: ScoringWeights) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(Self::action_weights() != weights, Error::<T>::NoUpdatesForActionWeights);
+
+            ActionWeights::put(weights.clone());
+            Self::deposit_event(RawEvent::ActionWeightsUpdated(weights));
+            Ok(())
+        }
+
+        /// Recalculate account reputation from all the reputation diffs this account has received
+        /// and apply the reputation decay to the result.
+        ///
+        /// `reputation_diffs_count` should be at least `ReputationDiffsCountBySubject` of this account.
+        #[weight = 100_000 + T::DbWeight::get().reads_writes(4 + *reputation_diffs_count as u64, 2)]
+        pub fn recalculate_reputation(
+            origin,
+            account: T::AccountId,
+            reputation_diffs_count: u32
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(Self::reputation_diffs_migrated(), Error::<T>::ReputationDiffsNotMigrated);
+
+            ensure!(
+                Self::reputation_diffs_count_by_subject(&account) <= reputation_diffs_count,
+                Error::<T>::TooLowReputationDiffsCount
+            );
+
+            let replayed_reputation = ReputationDiffBySubject::<T>::iter_prefix(&account)
+                .fold(1i64, |reputation, (_, diff)| reputation.saturating_add(diff as i64));
+
+            let replayed_reputation = replayed_reputation.max(1).min(u32::MAX as i64) as u32;
+
+            let mut social_account = Profiles::get_or_new_social_account(account.clone());
+            Self::set_reputation_with_decay(&account, &mut social_account, replayed_reputation);
+            <SocialAccountById<T>>::insert(account.clone(), social_account.clone());
+
+            Self::deposit_event(RawEvent::ReputationRecalculated(account, social_account.reputation));
+            Ok(())
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
 
+    /// Move up to `max_diffs` reputation diffs from the deprecated `AccountReputationDiffByAccount` map
+    /// to `ReputationDiffByActor` and add them to `ReputationDiffBySubject`.
+    ///
+    /// Old diffs are removed while migrating. `ReputationDiffsMigrated` is set once the old map is empty.
+    pub fn migrate_reputation_diffs(max_diffs: usize) -> Weight {
+        if Self::reputation_diffs_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut diffs: usize = 0;
+
+        for ((actor, subject, action), diff) in AccountReputationDiffByAccount::<T>::drain().take(max_diffs) {
+            diffs += 1;
+
+            Self::add_reputation_diff_by_subject(&subject, &actor, action, diff as i64);
+            ReputationDiffByActor::<T>::insert((actor, subject, action), diff);
+        }
+
+        if diffs < max_diffs {
+            ReputationDiffsMigrated::put(true);
+        }
+
+        T::DbWeight::get().reads_writes(
+            2 + diffs as Weight * 3,
+            1 + diffs as Weight * 4
+        )
+    }
+
+    /// A reputation diff the subject has received from the actor by the action,
+    /// whether it is migrated to `ReputationDiffByActor` or not.
+    pub fn account_reputation_diff_by_account(
+        key: (T::AccountId, T::AccountId, ScoringAction)
+    ) -> Option<i16> {
+        ReputationDiffByActor::<T>::get(&key)
+            .or_else(|| AccountReputationDiffByAccount::<T>::get(&key))
+    }
+
     pub fn scoring_action_by_post_extension(
         extension: PostExtension,
         reaction_kind: ReactionKind,
@@ -212,7 +387,8 @@ impl<T: Trait> Module<T> {
                 }
                 _ => (),
             }
-            let score_diff = Self::score_diff_for_action(social_account.reputation, action);
+            let actor_reputation = Self::reputation_with_decay(&account, social_account.reputation);
+            let score_diff = Self::score_diff_for_action(actor_reputation, action);
             post.change_score(score_diff);
             space.change_score(score_diff);
             Self::change_social_account_reputation(post.owner.clone(), account.clone(), score_diff, action)?;
@@ -273,7 +449,8 @@ impl<T: Trait> Module<T> {
                 }
                 _ => (),
             }
-            let score_diff = Self::score_diff_for_action(social_account.reputation, action);
+            let actor_reputation = Self::reputation_with_decay(&account, social_account.reputation);
+            let score_diff = Self::score_diff_for_action(actor_reputation, action);
             comment.change_score(score_diff);
             Self::change_social_account_reputation(comment.owner.clone(), account.clone(), score_diff, action)?;
             <PostScoreByAccount<T>>::insert((account, comment_id, action), score_diff);
@@ -295,6 +472,9 @@ impl<T: Trait> Module<T> {
 
         // TODO seems like we can pass a &mut social account as an arg to this func
         let mut social_account = Profiles::get_or_new_social_account(account.clone());
+        let reputation = social_account.reputation;
+        Self::set_reputation_with_decay(&account, &mut social_account, reputation);
+        let decayed_reputation = social_account.reputation;
 
         if social_account.reputation as i64 + score_diff as i64 <= 1 {
             social_account.reputation = 1;
@@ -303,10 +483,18 @@ impl<T: Trait> Module<T> {
 
         social_account.change_reputation(score_diff);
 
-        if Self::account_reputation_diff_by_account((scorer.clone(), account.clone(), action)).is_some() {
-            <AccountReputationDiffByAccount<T>>::remove((scorer, account.clone(), action));
+        let applied_diff = social_account.reputation as i64 - decayed_reputation as i64;
+        let key = (scorer.clone(), account.clone(), action);
+
+        if ReputationDiffByActor::<T>::contains_key(&key) {
+            ReputationDiffByActor::<T>::remove(&key);
+            Self::add_reputation_diff_by_subject(&account, &scorer, action, applied_diff);
+        } else if AccountReputationDiffByAccount::<T>::contains_key(&key) {
+            // This diff is not counted in `ReputationDiffBySubject` yet, so its revert is not counted either.
+            AccountReputationDiffByAccount::<T>::remove(&key);
         } else {
-            <AccountReputationDiffByAccount<T>>::insert((scorer, account.clone(), action), score_diff);
+            ReputationDiffByActor::<T>::insert(&key, score_diff);
+            Self::add_reputation_diff_by_subject(&account, &scorer, action, applied_diff);
         }
 
         <SocialAccountById<T>>::insert(account.clone(), social_account.clone());
@@ -316,6 +504,33 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Add a reputation change to the sum of changes the subject has received from the actor by the action.
+    fn add_reputation_diff_by_subject(
+        subject: &T::AccountId,
+        actor: &T::AccountId,
+        action: ScoringAction,
+        diff: i64,
+    ) {
+        if diff == 0 {
+            return
+        }
+
+        let key = (actor.clone(), action);
+        let is_new_entry = !ReputationDiffBySubject::<T>::contains_key(subject, &key);
+        let new_diff = Self::reputation_diff_by_subject(subject, &key)
+            .saturating_add(diff.max(i32::MIN as i64).min(i32::MAX as i64) as i32);
+
+        if new_diff == 0 {
+            ReputationDiffBySubject::<T>::remove(subject, &key);
+            ReputationDiffsCountBySubject::<T>::mutate(subject, |count| *count = count.saturating_sub(1));
+        } else {
+            ReputationDiffBySubject::<T>::insert(subject, &key, new_diff);
+            if is_new_entry {
+                ReputationDiffsCountBySubject::<T>::mutate(subject, |count| *count = count.saturating_add(1));
+            }
+        }
+    }
+
     pub fn score_diff_for_action(reputation: u32, action: ScoringAction) -> i16 {
         Self::smooth_reputation(reputation) as i16 * Self::weight_of_scoring_action(action)
     }
@@ -331,18 +546,50 @@ impl<T: Trait> Module<T> {
     }
 
     fn weight_of_scoring_action(action: ScoringAction) -> i16 {
-        use ScoringAction::*;
-        match action {
-            UpvotePost => T::UpvotePostActionWeight::get(),
-            DownvotePost => T::DownvotePostActionWeight::get(),
-            SharePost => T::SharePostActionWeight::get(),
-            CreateComment => T::CreateCommentActionWeight::get(),
-            UpvoteComment => T::UpvoteCommentActionWeight::get(),
-            DownvoteComment => T::DownvoteCommentActionWeight::get(),
-            ShareComment => T::ShareCommentActionWeight::get(),
-            FollowSpace => T::FollowSpaceActionWeight::get(),
-            FollowAccount => T::FollowAccountActionWeight::get(),
+        Self::action_weights().weight_of(action)
+    }
+
+    /// Halve the part of reputation above the minimum (1) as many times as `halvings`.
+    pub fn decay_reputation(reputation: u32, halvings: u32) -> u32 {
+        if reputation <= 1 {
+            return reputation
         }
+
+        let excess = reputation - 1;
+        1 + excess.checked_shr(halvings).unwrap_or(0)
+    }
+
+    /// Get reputation of an account with the decay applied for all the full half-lives
+    /// that passed since the last decay. Does not change the storage.
+    pub fn reputation_with_decay(account: &T::AccountId, reputation: u32) -> u32 {
+        Self::calc_reputation_decay(account, reputation).0
+    }
+
+    /// Returns decayed reputation and a block number until which the decay was applied.
+    fn calc_reputation_decay(account: &T::AccountId, reputation: u32) -> (u32, T::BlockNumber) {
+        let half_life = T::ReputationHalfLife::get();
+        let current_block = <system::Module<T>>::block_number();
+
+        // An account that has never decayed starts to decay from now on.
+        if half_life.is_zero() || reputation <= 1 || !LastReputationDecayAt::<T>::contains_key(account) {
+            return (reputation, current_block)
+        }
+
+        let last_decay_at = Self::last_reputation_decay_at(account);
+        let halvings = current_block.saturating_sub(last_decay_at) / half_life;
+        let decayed_until = last_decay_at.saturating_add(halvings.saturating_mul(half_life));
+
+        (Self::decay_reputation(reputation, halvings.unique_saturated_into()), decayed_until)
+    }
+
+    fn set_reputation_with_decay(
+        account: &T::AccountId,
+        social_account: &mut SocialAccount<T>,
+        reputation: u32,
+    ) {
+        let (decayed_reputation, decayed_until) = Self::calc_reputation_decay(account, reputation);
+        social_account.reputation = decayed_reputation;
+        LastReputationDecayAt::<T>::insert(account, decayed_until);
     }
 }
 
@@ -352,6 +599,7 @@ impl<T: Trait> BeforeSpaceFollowed<T> for Module<T> {
         if !space.is_owner(&follower) {
             let space_owner = space.owner.clone();
             let action = ScoringAction::FollowSpace;
+            let follower_reputation = Self::reputation_with_decay(&follower, follower_reputation);
             let score_diff = Self::score_diff_for_action(follower_reputation, action);
             space.change_score(score_diff);
             return Self::change_social_account_reputation(
@@ -383,6 +631,7 @@ impl<T: Trait> BeforeSpaceUnfollowed<T> for Module<T> {
 impl<T: Trait> BeforeAccountFollowed<T> for Module<T> {
     fn before_account_followed(follower: T::AccountId, follower_reputation: u32, following: T::AccountId) -> DispatchResult {
         let action = ScoringAction::FollowAccount;
+        let follower_reputation = Self::reputation_with_decay(&follower, follower_reputation);
         let score_diff = Self::score_diff_for_action(follower_reputation, action);
         Self::change_social_account_reputation(following, follower, score_diff, action)
     }
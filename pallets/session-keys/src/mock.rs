@@ -104,6 +104,8 @@ parameter_types! {
   pub const IpfsCidLen: u32 = 46;
   pub const MinHandleLen: u32 = 5;
   pub const MaxHandleLen: u32 = 50;
+  pub const MaxRawContentLen: u32 = 64;
+  pub const RawContentDepositPerByte: u64 = 0;
 }
 
 impl pallet_utils::Trait for Test {
@@ -111,6 +113,9 @@ impl pallet_utils::Trait for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type MaxRawContentLen = MaxRawContentLen;
+    type RawContentDepositPerByte = RawContentDepositPerByte;
+    type ContentValidator = pallet_utils::DefaultContentValidator<Test>;
 }
 
 impl pallet_profile_follows::Trait for Test {
@@ -3,9 +3,11 @@
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure,
     dispatch::DispatchResult,
-    traits::Get
+    storage::IterableStorageMap,
+    traits::Get,
+    weights::Weight,
 };
-use sp_std::prelude::*;
+use sp_std::{cmp::min, prelude::*};
 use frame_system::{self as system, ensure_signed};
 
 use df_traits::{
@@ -14,10 +16,13 @@ use df_traits::{
 };
 use pallet_profiles::{Module as Profiles, SocialAccountById};
 use pallet_spaces::{BeforeSpaceCreated, Module as Spaces, Space, SpaceById};
-use pallet_utils::{Error as UtilsError, SpaceId, remove_from_vec};
+use pallet_utils::{Error as UtilsError, SpaceId};
 
 pub mod rpc;
 
+/// Max number of deprecated follower list entries moved to double maps in a single block.
+pub const MAX_FOLLOWERS_TO_MIGRATE_PER_BLOCK: usize = 1_000;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
@@ -48,14 +53,26 @@ decl_error! {
 // This pallet's storage items.
 decl_storage! {
     trait Store for Module<T: Trait> as SpaceFollowsModule {
-        pub SpaceFollowers get(fn space_followers):
-            map hasher(twox_64_concat) SpaceId => Vec<T::AccountId>;
+        /// Followers of a space. The number of followers is stored in `Space.followers_count`.
+        pub FollowersBySpace get(fn space_follower):
+            double_map hasher(twox_64_concat) SpaceId, hasher(blake2_128_concat) T::AccountId => bool;
 
         pub SpaceFollowedByAccount get(fn space_followed_by_account):
             map hasher(blake2_128_concat) (T::AccountId, SpaceId) => bool;
 
-        pub SpacesFollowedByAccount get(fn spaces_followed_by_account):
-            map hasher(blake2_128_concat) T::AccountId => Vec<SpaceId>;
+        /// Spaces followed by an account.
+        /// The number of followed spaces is stored in `SocialAccount.following_spaces_count`.
+        pub FollowedSpacesByAccount get(fn followed_space):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) SpaceId => bool;
+
+        /// Deprecated: replaced with `FollowersBySpace` and drained in `on_initialize`.
+        pub SpaceFollowers: map hasher(twox_64_concat) SpaceId => Vec<T::AccountId>;
+
+        /// Deprecated: replaced with `FollowedSpacesByAccount` and drained in `on_initialize`.
+        pub SpacesFollowedByAccount: map hasher(blake2_128_concat) T::AccountId => Vec<SpaceId>;
+
+        /// Whether the deprecated follower lists were fully moved to double maps.
+        pub FollowersMigrated get(fn followers_migrated): bool;
     }
 }
 
@@ -77,6 +94,14 @@ decl_module! {
     // Initializing events
     fn deposit_event() = default;
 
+    fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+      if Self::followers_migrated() {
+        return T::DbWeight::get().reads(1);
+      }
+
+      Self::migrate_followers_to_double_maps(MAX_FOLLOWERS_TO_MIGRATE_PER_BLOCK)
+    }
+
     #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
     pub fn follow_space(origin, space_id: SpaceId) -> DispatchResult {
       let follower = ensure_signed(origin)?;
@@ -116,9 +141,9 @@ impl<T: Trait> Module<T> {
             follower.clone(), social_account.reputation, space)?;
 
         let space_id = space.id;
-        <SpaceFollowers<T>>::mutate(space_id, |followers| followers.push(follower.clone()));
+        <FollowersBySpace<T>>::insert(space_id, follower.clone(), true);
         <SpaceFollowedByAccount<T>>::insert((follower.clone(), space_id), true);
-        <SpacesFollowedByAccount<T>>::mutate(follower.clone(), |space_ids| space_ids.push(space_id));
+        <FollowedSpacesByAccount<T>>::insert(follower.clone(), space_id, true);
         <SocialAccountById<T>>::insert(follower.clone(), social_account);
 
         Self::deposit_event(RawEvent::SpaceFollowed(follower, space_id));
@@ -135,8 +160,8 @@ impl<T: Trait> Module<T> {
 
         T::BeforeSpaceUnfollowed::before_space_unfollowed(follower.clone(), space)?;
 
-        <SpacesFollowedByAccount<T>>::mutate(follower.clone(), |space_ids| remove_from_vec(space_ids, space_id));
-        <SpaceFollowers<T>>::mutate(space_id, |account_ids| remove_from_vec(account_ids, follower.clone()));
+        <FollowedSpacesByAccount<T>>::remove(follower.clone(), space_id);
+        <FollowersBySpace<T>>::remove(space_id, follower.clone());
         <SpaceFollowedByAccount<T>>::remove((follower.clone(), space_id));
         <SocialAccountById<T>>::insert(follower.clone(), social_account);
         <SpaceById<T>>::insert(space_id, space);
@@ -144,6 +169,73 @@ impl<T: Trait> Module<T> {
         Self::deposit_event(RawEvent::SpaceUnfollowed(follower, space_id));
         Ok(())
     }
+
+    /// Move up to `max_followers` entries of the deprecated `SpaceFollowers` and `SpacesFollowedByAccount` lists
+    /// to the `FollowersBySpace` and `FollowedSpacesByAccount` double maps.
+    ///
+    /// Migrated entries are removed from the old lists, so the rest of a long list is migrated in the next blocks.
+    /// Entries of accounts that unfollowed a space before they were migrated are dropped.
+    /// `FollowersMigrated` is set once both old maps are empty.
+    pub fn migrate_followers_to_double_maps(max_followers: usize) -> Weight {
+        // An empty list is counted as one entry, so the number of lists is bounded too.
+        let mut entries: usize = 0;
+        let mut lists: Weight = 0;
+
+        while entries < max_followers {
+            let (space_id, mut followers) = match SpaceFollowers::<T>::iter().next() {
+                Some(list) => list,
+                None => break,
+            };
+            lists += 1;
+
+            let rest = followers.split_off(min(followers.len(), max_followers - entries));
+            entries += followers.len().max(1);
+
+            for follower in followers {
+                if Self::space_followed_by_account((follower.clone(), space_id)) {
+                    <FollowersBySpace<T>>::insert(space_id, follower, true);
+                }
+            }
+
+            if rest.is_empty() {
+                SpaceFollowers::<T>::remove(space_id);
+            } else {
+                SpaceFollowers::<T>::insert(space_id, rest);
+            }
+        }
+
+        while entries < max_followers {
+            let (follower, mut space_ids) = match SpacesFollowedByAccount::<T>::iter().next() {
+                Some(list) => list,
+                None => break,
+            };
+            lists += 1;
+
+            let rest = space_ids.split_off(min(space_ids.len(), max_followers - entries));
+            entries += space_ids.len().max(1);
+
+            for space_id in space_ids {
+                if Self::space_followed_by_account((follower.clone(), space_id)) {
+                    <FollowedSpacesByAccount<T>>::insert(follower.clone(), space_id, true);
+                }
+            }
+
+            if rest.is_empty() {
+                SpacesFollowedByAccount::<T>::remove(&follower);
+            } else {
+                SpacesFollowedByAccount::<T>::insert(&follower, rest);
+            }
+        }
+
+        if entries < max_followers {
+            FollowersMigrated::put(true);
+        }
+
+        T::DbWeight::get().reads_writes(
+            3 + lists + entries as Weight,
+            1 + lists + entries as Weight
+        )
+    }
 }
 
 impl<T: Trait> SpaceFollowsProvider for Module<T> {
@@ -1,12 +1,15 @@
+use frame_support::storage::IterableStorageDoubleMap;
 use sp_std::prelude::*;
 
 use pallet_utils::SpaceId;
 
-use crate::{Module, Trait};
+use crate::{FollowedSpacesByAccount, FollowersBySpace, Module, Trait};
 
 impl<T: Trait> Module<T> {
     pub fn get_space_ids_followed_by_account(account: T::AccountId) -> Vec<SpaceId> {
-        Self::spaces_followed_by_account(account)
+        FollowedSpacesByAccount::<T>::iter_prefix(account)
+            .map(|(space_id, _)| space_id)
+            .collect()
     }
 
     pub fn filter_followed_space_ids(account: T::AccountId, space_ids: Vec<SpaceId>) -> Vec<SpaceId> {
@@ -14,4 +17,12 @@ impl<T: Trait> Module<T> {
             .filter(|space_id| Self::space_followed_by_account((&account, space_id)))
             .cloned().collect()
     }
-}
\ No newline at end of file
+
+    pub fn get_space_followers(space_id: SpaceId, offset: u64, limit: u16) -> Vec<T::AccountId> {
+        FollowersBySpace::<T>::iter_prefix(space_id)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(follower, _)| follower)
+            .collect()
+    }
+}
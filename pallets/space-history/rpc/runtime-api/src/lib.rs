@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_space_history::rpc::FlatSpaceHistoryRecord;
+use pallet_utils::SpaceId;
+
+sp_api::decl_runtime_apis! {
+    pub trait SpaceHistoryApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_space_edit_history(
+            space_id: SpaceId,
+            offset: u64,
+            limit: u16,
+        ) -> Vec<FlatSpaceHistoryRecord<AccountId, BlockNumber>>;
+    }
+}
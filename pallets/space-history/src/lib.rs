@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{decl_module, decl_storage};
+use frame_support::{decl_module, decl_storage, traits::Get};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
 use frame_system::{self as system};
@@ -9,6 +9,8 @@ use frame_system::{self as system};
 use pallet_utils::{SpaceId, WhoAndWhen};
 use pallet_spaces::{Space, SpaceUpdate, AfterSpaceUpdated};
 
+pub mod rpc;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
 pub struct SpaceHistoryRecord<T: Trait> {
     pub edited: WhoAndWhen<T>,
@@ -19,7 +21,11 @@ pub struct SpaceHistoryRecord<T: Trait> {
 pub trait Trait: system::Trait
     + pallet_spaces::Trait
     + pallet_utils::Trait
-{}
+{
+    /// Max number of edit history records stored per entity.
+    /// The oldest records are removed when this limit is reached.
+    type MaxHistoryRecords: Get<u16>;
+}
 
 // This pallet's storage items.
 decl_storage! {
@@ -31,7 +37,10 @@ decl_storage! {
 
 // The pallet's dispatchable functions.
 decl_module! {
-  pub struct Module<T: Trait> for enum Call where origin: T::Origin {}
+  pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+    /// Max number of edit history records stored per entity.
+    const MaxHistoryRecords: u16 = T::MaxHistoryRecords::get();
+  }
 }
 
 impl<T: Trait> SpaceHistoryRecord<T> {
@@ -45,7 +54,14 @@ impl<T: Trait> SpaceHistoryRecord<T> {
 
 impl<T: Trait> AfterSpaceUpdated<T> for Module<T> {
     fn after_space_updated(sender: T::AccountId, space: &Space<T>, old_data: SpaceUpdate) {
-        <EditHistory<T>>::mutate(space.id, |ids|
-            ids.push(SpaceHistoryRecord::<T>::new(sender, old_data)));
+        <EditHistory<T>>::mutate(space.id, |records| {
+            records.push(SpaceHistoryRecord::<T>::new(sender, old_data));
+
+            let max_records = T::MaxHistoryRecords::get() as usize;
+            if records.len() > max_records {
+                let records_to_remove = records.len() - max_records;
+                records.drain(..records_to_remove);
+            }
+        });
     }
 }
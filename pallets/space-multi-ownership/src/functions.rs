@@ -1,15 +1,37 @@
 use super::*;
 
 use sp_std::collections::btree_set::BTreeSet;
-use frame_support::{dispatch::DispatchResult};
+use frame_support::{dispatch::DispatchResult, traits::OriginTrait};
 
 impl<T: Trait> Module<T> {
 
+  /// An account that owns a multi-owned space. It is derived from the space id,
+  /// so nobody knows its private key.
+  pub fn space_account_id(space_id: SpaceId) -> T::AccountId {
+    T::Spaces::space_account_id(space_id)
+  }
+
+  pub fn is_space_co_owner(account: &T::AccountId, space_id: SpaceId) -> bool {
+    Self::space_owners_by_space_id(space_id)
+      .map_or(false, |space_owners| space_owners.owners.contains(account))
+  }
+
+  /// Count confirmations of accounts that are still co-owners of a space.
+  /// Confirmations of removed co-owners are ignored.
+  pub fn count_confirmations_by_owners(space_owners: &SpaceOwners<T>, confirmed_by: &[T::AccountId]) -> usize {
+    confirmed_by.iter()
+      .filter(|account| space_owners.owners.contains(account))
+      .count()
+  }
+
   pub fn update_space_owners(who: T::AccountId, mut space_owners: SpaceOwners<T>, change: Change<T>) -> DispatchResult {
     let space_id = space_owners.space_id;
     let change_id = change.id;
 
-    ensure!(change.confirmed_by.len() >= space_owners.threshold as usize, Error::<T>::NotEnoughConfirms);
+    ensure!(
+      Self::count_confirmations_by_owners(&space_owners, &change.confirmed_by) >= space_owners.threshold as usize,
+      Error::<T>::NotEnoughConfirms
+    );
     Self::move_change_from_pending_state_to_executed(space_id, change_id)?;
 
     space_owners.changes_count = space_owners.changes_count.checked_add(1).ok_or(Error::<T>::ChangesCountOverflow)?;
@@ -64,17 +86,79 @@ impl<T: Trait> Module<T> {
     owners_set.iter().cloned().collect()
   }
 
-  pub fn delete_expired_changes(block_number: T::BlockNumber) {
-    if (block_number % T::DeleteExpiredChangesPeriod::get()).is_zero() {
-      for change_id in Self::pending_change_ids() {
-        if let Some(change) = Self::change_by_id(change_id) {
-          if block_number >= change.expires_at {
-            PendingChangeIdBySpaceId::remove(&change.space_id);
-            <ChangeById<T>>::remove(&change_id);
-            PendingChangeIds::mutate(|set| set.remove(&change_id));
-          }
+  /// Dispatch a call of the proposal as a space account.
+  /// Only calls that pass `T::CallFilter` can be dispatched.
+  pub(crate) fn execute_proposal(proposal: Proposal<T>) {
+    let space_account = Self::space_account_id(proposal.space_id);
+
+    let mut origin: T::Origin = frame_system::RawOrigin::Signed(space_account).into();
+    origin.add_filter(move |c: &<T as frame_system::Trait>::Call| {
+      let c = <T as Trait>::Call::from_ref(c);
+      T::CallFilter::filter(c)
+    });
+
+    let result = proposal.call.dispatch(origin);
+    Self::deposit_event(RawEvent::CallExecuted(
+      proposal.space_id,
+      proposal.id,
+      result.map(|_| ()).map_err(|e| e.error)
+    ));
+  }
+
+  /// Remove a pending call proposal and release its deposit.
+  pub(crate) fn remove_pending_proposal(proposal: &Proposal<T>) {
+    let proposal_id = proposal.id;
+    <ProposalById<T>>::remove(proposal_id);
+    PendingProposalIdsBySpaceId::mutate(proposal.space_id, |ids| ids.retain(|id| *id != proposal_id));
+    <ProposalIdsExpiringAt<T>>::mutate(proposal.expires_at, |ids| ids.retain(|id| *id != proposal_id));
+    <T as pallet_utils::Trait>::Currency::unreserve(&proposal.created.account, proposal.deposit);
+  }
+
+  /// Remove pending changes that are expired by `block_number`. Returns the weight of this removal.
+  pub fn delete_expired_changes(block_number: T::BlockNumber) -> Weight {
+    if !(block_number % T::DeleteExpiredChangesPeriod::get()).is_zero() {
+      return 0;
+    }
+
+    let pending_change_ids = Self::pending_change_ids();
+    let changes_count = pending_change_ids.len() as Weight;
+    let mut deleted_count: Weight = 0;
+
+    for change_id in pending_change_ids {
+      if let Some(change) = Self::change_by_id(change_id) {
+        if block_number >= change.expires_at {
+          PendingChangeIdBySpaceId::remove(&change.space_id);
+          <ChangeById<T>>::remove(&change_id);
+          PendingChangeIds::mutate(|set| set.remove(&change_id));
+          deleted_count += 1;
         }
       }
     }
+
+    T::DbWeight::get().reads_writes(1 + changes_count, deleted_count.saturating_mul(3))
+  }
+
+  /// Remove call proposals that expire at `block_number`. Returns the weight of this removal.
+  pub fn delete_expired_proposals(block_number: T::BlockNumber) -> Weight {
+    let proposal_ids = <ProposalIdsExpiringAt<T>>::take(block_number);
+    let proposals_count = proposal_ids.len() as Weight;
+
+    for proposal_id in proposal_ids {
+      if let Some(proposal) = Self::proposal_by_id(proposal_id) {
+        Self::remove_pending_proposal(&proposal);
+      }
+    }
+
+    // Each removal reads and writes a proposal, its space index and a deposit.
+    T::DbWeight::get().reads_writes(
+      1 + proposals_count.saturating_mul(4),
+      1 + proposals_count.saturating_mul(4)
+    )
+  }
+}
+
+impl<T: Trait> SpaceCoOwnersProvider<T::AccountId> for Module<T> {
+  fn is_space_co_owner(account: &T::AccountId, space_id: SpaceId) -> bool {
+    Module::<T>::is_space_co_owner(account, space_id)
   }
 }
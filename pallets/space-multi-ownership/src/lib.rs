@@ -6,11 +6,15 @@ use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_runtime::{RuntimeDebug, traits::Zero};
 
 use frame_support::{
-  decl_error, decl_event, decl_module, decl_storage, ensure,
-  traits::Get
+  decl_error, decl_event, decl_module, decl_storage, ensure, Parameter,
+  dispatch::{DispatchResult, PostDispatchInfo},
+  traits::{Currency, Get, Filter, IsType, ReservableCurrency},
+  weights::{GetDispatchInfo, Weight},
 };
+use sp_runtime::traits::{Dispatchable, Saturating};
 use frame_system::{self as system, ensure_signed};
 
+use df_traits::{CallSpaceResolver, SpaceCoOwnersProvider, SpaceOwnerManager};
 use pallet_utils::{SpaceId, WhoAndWhen};
 
 pub mod functions;
@@ -42,6 +46,24 @@ pub struct Change<T: Trait> {
 
 type ChangeId = u64;
 
+/// A proposal to dispatch an arbitrary call on behalf of a multi-owned space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Proposal<T: Trait> {
+  pub created: WhoAndWhen<T>,
+  pub id: ProposalId,
+  pub space_id: SpaceId,
+  pub call: Box<<T as Trait>::Call>,
+  pub notes: Vec<u8>,
+  pub confirmed_by: Vec<T::AccountId>,
+  pub expires_at: T::BlockNumber,
+  /// A deposit reserved from the proposal creator until the proposal is executed, canceled or expired.
+  pub deposit: BalanceOf<T>,
+}
+
+pub type ProposalId = u64;
+
+type BalanceOf<T> = <<T as pallet_utils::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
   + pallet_timestamp::Trait
@@ -50,6 +72,24 @@ pub trait Trait: system::Trait
   /// The overarching event type.
   type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
+  /// The overarching call type.
+  type Call: Parameter
+    + Dispatchable<Origin=Self::Origin, PostInfo=PostDispatchInfo>
+    + GetDispatchInfo + From<frame_system::Call<Self>>
+    + IsType<<Self as frame_system::Trait>::Call>;
+
+  /// Filters calls that co-owners can propose to dispatch on behalf of a space.
+  type CallFilter: Filter<<Self as Trait>::Call>;
+
+  /// Resolves a space of a proposed call. Only calls within the space of a proposal can be proposed.
+  type CallSpaceResolver: CallSpaceResolver<<Self as Trait>::Call>;
+
+  /// A deposit per byte of an encoded call that is reserved from a creator of a pending call proposal.
+  type ProposalDepositPerByte: Get<BalanceOf<Self>>;
+
+  /// Used to check the current owner of a space and to pass its ownership to a space account.
+  type Spaces: SpaceOwnerManager<AccountId=Self::AccountId>;
+
   /// Minimum space owners allowed.
   type MinSpaceOwners: Get<u16>;
 
@@ -113,6 +153,21 @@ decl_error! {
 
     /// Overflow when incrementing a counter of executed changes
     ChangesCountOverflow,
+
+    /// Proposal was not found by id
+    ProposalNotFound,
+    /// Proposal is not related to this space
+    ProposalNotRelatedToSpace,
+    /// Account has already confirmed this proposal
+    ProposalAlreadyConfirmed,
+    /// Account is not a proposal creator
+    NotAProposalCreator,
+    /// This call is not allowed to be dispatched on behalf of a space
+    CallIsNotAllowed,
+    /// This call does not act within the space of a proposal
+    CallIsNotRelatedToSpace,
+    /// Weight of a proposed call is greater than a provided max weight
+    MaxWeightTooLow,
   }
 }
 
@@ -137,6 +192,18 @@ decl_storage! {
 
     ExecutedChangeIdsBySpaceId get(fn executed_change_ids_by_space_id):
       map hasher(twox_64_concat) SpaceId => Vec<ChangeId>;
+
+    NextProposalId get(fn next_proposal_id): ProposalId = 1;
+
+    ProposalById get(fn proposal_by_id):
+      map hasher(twox_64_concat) ProposalId => Option<Proposal<T>>;
+
+    PendingProposalIdsBySpaceId get(fn pending_proposal_ids_by_space_id):
+      map hasher(twox_64_concat) SpaceId => Vec<ProposalId>;
+
+    /// Pending call proposals that expire at a given block.
+    ProposalIdsExpiringAt get(fn proposal_ids_expiring_at):
+      map hasher(twox_64_concat) T::BlockNumber => Vec<ProposalId>;
   }
 }
 
@@ -158,14 +225,18 @@ decl_module! {
     /// Period in blocks to initialize deleting of pending changes that are outdated.
     const DeleteExpiredChangesPeriod: T::BlockNumber = T::DeleteExpiredChangesPeriod::get();
 
+    /// A deposit per byte of an encoded call that is reserved from a creator of a pending call proposal.
+    const ProposalDepositPerByte: BalanceOf<T> = T::ProposalDepositPerByte::get();
+
     // Initializing events
     fn deposit_event() = default;
 
-    fn on_finalize(n: T::BlockNumber) {
-      Self::delete_expired_changes(n);
+    fn on_initialize(n: T::BlockNumber) -> Weight {
+      Self::delete_expired_changes(n)
+        .saturating_add(Self::delete_expired_proposals(n))
     }
 
-    #[weight = T::DbWeight::get().reads_writes(2, 2) + 10_000]
+    #[weight = T::DbWeight::get().reads_writes(4, 5) + 10_000]
     pub fn create_space_owners(
       origin,
       space_id: SpaceId,
@@ -175,6 +246,7 @@ decl_module! {
       let who = ensure_signed(origin)?;
 
       ensure!(Self::space_owners_by_space_id(space_id).is_none(), Error::<T>::SpaceOwnersAlreadyExist);
+      ensure!(T::Spaces::get_space_owner(space_id)? == who, Error::<T>::NotASpaceOwner);
 
       let mut owners_map: BTreeMap<T::AccountId, bool> = BTreeMap::new();
       let mut unique_owners: Vec<T::AccountId> = Vec::new();
@@ -201,6 +273,9 @@ decl_module! {
         changes_count: 0
       };
 
+      // From now on a space is owned by its account, so only a threshold of co-owners can control it.
+      T::Spaces::set_space_owner(space_id, Self::space_account_id(space_id))?;
+
       <SpaceOwnersBySpaceById<T>>::insert(space_id, new_space_owners);
 
       for owner in unique_owners.iter() {
@@ -300,7 +375,7 @@ decl_module! {
 
       change.confirmed_by.push(who.clone());
 
-      if change.confirmed_by.len() == space_owners.threshold as usize {
+      if Self::count_confirmations_by_owners(&space_owners, &change.confirmed_by) >= space_owners.threshold as usize {
         Self::update_space_owners(who.clone(), space_owners, change)?;
       } else {
         <ChangeById<T>>::insert(change_id, change);
@@ -334,6 +409,125 @@ decl_module! {
 
       Self::deposit_event(RawEvent::ProposalCanceled(who, space_id));
     }
+
+    /// Propose to dispatch a `call` on behalf of a space account.
+    /// The call is dispatched as soon as `threshold` of co-owners confirm it.
+    #[weight = {
+      let dispatch_info = call.get_dispatch_info();
+      (
+        T::DbWeight::get().reads_writes(5, 5)
+          .saturating_add(dispatch_info.weight)
+          .saturating_add(10_000),
+        dispatch_info.class,
+      )
+    }]
+    pub fn propose_call(
+      origin,
+      space_id: SpaceId,
+      call: Box<<T as Trait>::Call>,
+      notes: Vec<u8>
+    ) {
+      let who = ensure_signed(origin)?;
+
+      ensure!(notes.len() <= T::MaxChangeNotesLength::get() as usize, Error::<T>::ChangeNotesOversize);
+      ensure!(T::CallFilter::filter(&call), Error::<T>::CallIsNotAllowed);
+      ensure!(T::CallSpaceResolver::resolve_space_id(&call) == Some(space_id), Error::<T>::CallIsNotRelatedToSpace);
+
+      let space_owners = Self::space_owners_by_space_id(space_id).ok_or(Error::<T>::SpaceOwnersNotFound)?;
+      ensure!(space_owners.owners.contains(&who), Error::<T>::NotASpaceOwner);
+
+      let is_executed_at_once = space_owners.threshold <= 1;
+
+      // Only a pending proposal is stored on chain, so it is the only one that requires a deposit.
+      let deposit = if is_executed_at_once {
+        Zero::zero()
+      } else {
+        T::ProposalDepositPerByte::get().saturating_mul((call.encode().len() as u32).into())
+      };
+      <T as pallet_utils::Trait>::Currency::reserve(&who, deposit)?;
+
+      let proposal_id = Self::next_proposal_id();
+      let proposal = Proposal {
+        created: WhoAndWhen::<T>::new(who.clone()),
+        id: proposal_id,
+        space_id,
+        call,
+        notes,
+        confirmed_by: vec![who.clone()],
+        expires_at: <system::Module<T>>::block_number() + T::BlocksToLive::get(),
+        deposit,
+      };
+
+      NextProposalId::mutate(|n| { *n += 1; });
+      Self::deposit_event(RawEvent::CallProposed(who, space_id, proposal_id));
+
+      if is_executed_at_once {
+        Self::execute_proposal(proposal);
+      } else {
+        PendingProposalIdsBySpaceId::mutate(space_id, |ids| ids.push(proposal_id));
+        <ProposalIdsExpiringAt<T>>::mutate(proposal.expires_at, |ids| ids.push(proposal_id));
+        <ProposalById<T>>::insert(proposal_id, proposal);
+      }
+    }
+
+    /// Confirm a pending call proposal. The confirmation that reaches the threshold
+    /// dispatches the call, so `max_weight` should cover the weight of the proposed call.
+    #[weight = T::DbWeight::get().reads_writes(4, 5)
+      .saturating_add(*max_weight)
+      .saturating_add(10_000)]
+    pub fn confirm_call(
+      origin,
+      space_id: SpaceId,
+      proposal_id: ProposalId,
+      max_weight: Weight
+    ) {
+      let who = ensure_signed(origin)?;
+
+      let space_owners = Self::space_owners_by_space_id(space_id).ok_or(Error::<T>::SpaceOwnersNotFound)?;
+      ensure!(space_owners.owners.contains(&who), Error::<T>::NotASpaceOwner);
+
+      let mut proposal = Self::proposal_by_id(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+      ensure!(proposal.space_id == space_id, Error::<T>::ProposalNotRelatedToSpace);
+      ensure!(!proposal.confirmed_by.contains(&who), Error::<T>::ProposalAlreadyConfirmed);
+
+      proposal.confirmed_by.push(who.clone());
+
+      let is_threshold_reached =
+        Self::count_confirmations_by_owners(&space_owners, &proposal.confirmed_by) >= space_owners.threshold as usize;
+      if is_threshold_reached {
+        ensure!(proposal.call.get_dispatch_info().weight <= max_weight, Error::<T>::MaxWeightTooLow);
+      }
+
+      Self::deposit_event(RawEvent::CallConfirmed(who, space_id, proposal_id));
+
+      if is_threshold_reached {
+        Self::remove_pending_proposal(&proposal);
+        Self::execute_proposal(proposal);
+      } else {
+        <ProposalById<T>>::insert(proposal_id, proposal);
+      }
+    }
+
+    /// Cancel a pending call proposal. Only a creator of the proposal can cancel it.
+    #[weight = T::DbWeight::get().reads_writes(4, 4) + 10_000]
+    pub fn cancel_call(
+      origin,
+      space_id: SpaceId,
+      proposal_id: ProposalId
+    ) {
+      let who = ensure_signed(origin)?;
+
+      let space_owners = Self::space_owners_by_space_id(space_id).ok_or(Error::<T>::SpaceOwnersNotFound)?;
+      ensure!(space_owners.owners.contains(&who), Error::<T>::NotASpaceOwner);
+
+      let proposal = Self::proposal_by_id(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+      ensure!(proposal.space_id == space_id, Error::<T>::ProposalNotRelatedToSpace);
+      ensure!(proposal.created.account == who, Error::<T>::NotAProposalCreator);
+
+      Self::remove_pending_proposal(&proposal);
+
+      Self::deposit_event(RawEvent::CallCanceled(who, space_id, proposal_id));
+    }
   }
 }
 
@@ -346,5 +540,10 @@ decl_event!(
     ProposalCanceled(AccountId, SpaceId),
     ChangeConfirmed(AccountId, SpaceId, ChangeId),
     SpaceOwnersUpdated(AccountId, SpaceId, ChangeId),
+    CallProposed(AccountId, SpaceId, ProposalId),
+    CallConfirmed(AccountId, SpaceId, ProposalId),
+    CallCanceled(AccountId, SpaceId, ProposalId),
+    /// A proposed call was dispatched on behalf of a space, with the given result.
+    CallExecuted(SpaceId, ProposalId, DispatchResult),
   }
 );
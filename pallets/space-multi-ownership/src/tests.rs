@@ -1,8 +1,14 @@
 use crate::*;
 
+use std::cell::RefCell;
+
 use sp_core::H256;
 use sp_io::TestExternalities;
-use frame_support::{impl_outer_origin, assert_ok, assert_noop, parameter_types, weights::Weight, dispatch::DispatchResult};
+use frame_support::{
+  impl_outer_origin, impl_outer_dispatch, assert_ok, assert_noop, parameter_types,
+  weights::Weight, dispatch::{DispatchError, DispatchResult},
+  traits::{Currency, Filter},
+};
 use sp_runtime::{
   traits::{BlakeTwo256, IdentityLookup}, testing::Header, Perbill,
 };
@@ -11,6 +17,13 @@ impl_outer_origin! {
   pub enum Origin for Test {}
 }
 
+impl_outer_dispatch! {
+  pub enum Call for Test where origin: Origin {
+    frame_system::System,
+    pallet_balances::Balances,
+  }
+}
+
 #[derive(Clone, Eq, PartialEq)]
 pub struct Test;
 
@@ -24,7 +37,7 @@ parameter_types! {
 impl system::Trait for Test {
   type BaseCallFilter = ();
   type Origin = Origin;
-  type Call = ();
+  type Call = Call;
   type Index = u64;
   type BlockNumber = u64;
   type Hash = H256;
@@ -77,6 +90,8 @@ impl pallet_balances::Trait for Test {
 parameter_types! {
   pub const MinHandleLen: u32 = 5;
   pub const MaxHandleLen: u32 = 50;
+  pub const MaxRawContentLen: u32 = 64;
+  pub const RawContentDepositPerByte: u64 = 0;
 }
 
 impl pallet_utils::Trait for Test {
@@ -84,6 +99,9 @@ impl pallet_utils::Trait for Test {
   type Currency = Balances;
   type MinHandleLen = MinHandleLen;
   type MaxHandleLen = MaxHandleLen;
+  type MaxRawContentLen = MaxRawContentLen;
+  type RawContentDepositPerByte = RawContentDepositPerByte;
+  type ContentValidator = pallet_utils::DefaultContentValidator<Test>;
 }
 
 parameter_types! {
@@ -92,10 +110,61 @@ parameter_types! {
 	pub const MaxChangeNotesLength: u16 = 1024;
 	pub const BlocksToLive: u64 = 302_400;
 	pub const DeleteExpiredChangesPeriod: u64 = 1800;
+	pub const ProposalDepositPerByte: u64 = 1;
+}
+
+thread_local! {
+  static SPACE_OWNER_BY_ID: RefCell<BTreeMap<SpaceId, AccountId>> =
+    RefCell::new(vec![(1, ACCOUNT1), (2, ACCOUNT3)].into_iter().collect());
+}
+
+pub struct MockSpaces;
+
+impl SpaceOwnerManager for MockSpaces {
+  type AccountId = AccountId;
+
+  fn get_space_owner(space_id: SpaceId) -> Result<AccountId, DispatchError> {
+    SPACE_OWNER_BY_ID.with(|owners| owners.borrow().get(&space_id).cloned())
+      .ok_or(DispatchError::Other("Space not found"))
+  }
+
+  fn set_space_owner(space_id: SpaceId, new_owner: AccountId) -> DispatchResult {
+    SPACE_OWNER_BY_ID.with(|owners| owners.borrow_mut().insert(space_id, new_owner));
+    Ok(())
+  }
+
+  fn space_account_id(space_id: SpaceId) -> AccountId {
+    1_000 + space_id
+  }
+}
+
+pub struct MockCallFilter;
+
+impl Filter<Call> for MockCallFilter {
+  fn filter(c: &Call) -> bool {
+    !matches!(c, Call::System(..))
+  }
+}
+
+/// Balance transfers to `ACCOUNT4` are treated as calls within the space 1.
+pub struct MockCallSpaceResolver;
+
+impl CallSpaceResolver<Call> for MockCallSpaceResolver {
+  fn resolve_space_id(c: &Call) -> Option<SpaceId> {
+    match c {
+      Call::Balances(pallet_balances::Call::transfer(ACCOUNT4, _)) => Some(1),
+      _ => None,
+    }
+  }
 }
 
 impl Trait for Test {
   type Event = ();
+  type Call = Call;
+  type CallFilter = MockCallFilter;
+  type CallSpaceResolver = MockCallSpaceResolver;
+  type ProposalDepositPerByte = ProposalDepositPerByte;
+  type Spaces = MockSpaces;
   type MinSpaceOwners = MinSpaceOwners;
   type MaxSpaceOwners = MaxSpaceOwners;
   type MaxChangeNotesLength = MaxChangeNotesLength;
@@ -118,7 +187,10 @@ impl ExtBuilder {
         .unwrap();
 
     let mut ext = TestExternalities::from(storage);
-    ext.execute_with(|| System::set_block_number(1));
+    ext.execute_with(|| {
+      System::set_block_number(1);
+      let _ = Balances::make_free_balance_be(&ACCOUNT1, 100);
+    });
 
     ext
   }
@@ -335,6 +407,56 @@ fn _cancel_change(
   )
 }
 
+fn transfer_call(amount: u64) -> Call {
+  Call::Balances(pallet_balances::Call::transfer(ACCOUNT4, amount))
+}
+
+fn _propose_default_call() -> DispatchResult {
+  _propose_call(None, None, None)
+}
+
+fn _propose_call(
+  origin: Option<Origin>,
+  space_id: Option<SpaceId>,
+  call: Option<Call>,
+) -> DispatchResult {
+  MultiOwnership::propose_call(
+    origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+    space_id.unwrap_or(1),
+    Box::new(call.unwrap_or_else(|| transfer_call(10))),
+    self::change_note(),
+  )
+}
+
+fn _confirm_default_call() -> DispatchResult {
+  _confirm_call(None, None, None)
+}
+
+fn _confirm_call(
+  origin: Option<Origin>,
+  proposal_id: Option<ProposalId>,
+  max_weight: Option<Weight>,
+) -> DispatchResult {
+  MultiOwnership::confirm_call(
+    origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+    1,
+    proposal_id.unwrap_or(1),
+    max_weight.unwrap_or_else(|| transfer_call(10).get_dispatch_info().weight),
+  )
+}
+
+fn _cancel_call(origin: Option<Origin>, proposal_id: Option<ProposalId>) -> DispatchResult {
+  MultiOwnership::cancel_call(
+    origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+    1,
+    proposal_id.unwrap_or(1),
+  )
+}
+
+fn fund_space_account(space_id: SpaceId) {
+  let _ = Balances::make_free_balance_be(&MultiOwnership::space_account_id(space_id), 100);
+}
+
 #[test]
 fn create_space_owners_should_work() {
   ExtBuilder::build().execute_with(|| {
@@ -353,6 +475,30 @@ fn create_space_owners_should_work() {
     assert_eq!(space_owners.space_id, 1);
     assert_eq!(space_owners.threshold, 2);
     assert_eq!(space_owners.changes_count, 0);
+
+    // Check that the space is owned by its account now
+    assert_eq!(MockSpaces::get_space_owner(1), Ok(MultiOwnership::space_account_id(1)));
+    assert!(MultiOwnership::is_space_co_owner(&ACCOUNT2, 1));
+    assert!(!MultiOwnership::is_space_co_owner(&ACCOUNT3, 1));
+  });
+}
+
+#[test]
+fn create_space_owners_should_fail_when_account_is_not_a_space_owner() {
+  ExtBuilder::build().execute_with(|| {
+    assert_noop!(_create_space_owners(
+      Some(Origin::signed(ACCOUNT2)),
+      None,
+      None,
+      None
+    ), Error::<Test>::NotASpaceOwner);
+  });
+}
+
+#[test]
+fn space_account_id_should_differ_between_spaces() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ne!(MultiOwnership::space_account_id(1), MultiOwnership::space_account_id(2));
   });
 }
 
@@ -666,3 +812,198 @@ fn cancel_proposal_should_fail_not_a_space_owner() {
      ), Error::<Test>::NotASpaceOwner);
   });
 }
+
+// Call proposals
+// -------
+
+#[test]
+fn confirm_call_should_dispatch_call_as_space_account() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    fund_space_account(1);
+
+    assert_ok!(_propose_default_call());
+
+    // The call should not be dispatched until the threshold is reached
+    assert_eq!(Balances::free_balance(ACCOUNT4), 0);
+    assert_eq!(MultiOwnership::pending_proposal_ids_by_space_id(1), vec![1]);
+
+    assert_ok!(_confirm_default_call());
+
+    assert_eq!(Balances::free_balance(ACCOUNT4), 10);
+    assert_eq!(Balances::free_balance(MultiOwnership::space_account_id(1)), 90);
+    assert!(MultiOwnership::proposal_by_id(1).is_none());
+    assert!(MultiOwnership::pending_proposal_ids_by_space_id(1).is_empty());
+    assert!(MultiOwnership::proposal_ids_expiring_at(1 + BlocksToLive::get()).is_empty());
+  });
+}
+
+#[test]
+fn confirm_call_should_not_count_confirmations_of_removed_owners() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_space_owners(None, None, Some(vec![ACCOUNT1, ACCOUNT2, ACCOUNT3]), Some(2)));
+    fund_space_account(1);
+
+    assert_ok!(_propose_default_call());
+
+    // Remove ACCOUNT1 that has confirmed the call proposal
+    assert_ok!(_propose_change(
+      Some(Origin::signed(ACCOUNT2)),
+      None,
+      Some(vec![]),
+      Some(vec![ACCOUNT1]),
+      Some(None),
+      None
+    ));
+    assert_ok!(_confirm_change(Some(Origin::signed(ACCOUNT3)), None, None));
+    assert_eq!(MultiOwnership::space_owners_by_space_id(1).unwrap().owners, vec![ACCOUNT2, ACCOUNT3]);
+
+    assert_ok!(_confirm_call(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_eq!(Balances::free_balance(ACCOUNT4), 0);
+    assert!(MultiOwnership::proposal_by_id(1).is_some());
+
+    assert_ok!(_confirm_call(Some(Origin::signed(ACCOUNT3)), None, None));
+    assert_eq!(Balances::free_balance(ACCOUNT4), 10);
+    assert!(MultiOwnership::proposal_by_id(1).is_none());
+  });
+}
+
+#[test]
+fn propose_call_should_reserve_deposit_until_call_is_dispatched() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    fund_space_account(1);
+
+    assert_ok!(_propose_default_call());
+
+    let deposit = transfer_call(10).encode().len() as u64;
+    assert_eq!(MultiOwnership::proposal_by_id(1).unwrap().deposit, deposit);
+    assert_eq!(Balances::reserved_balance(ACCOUNT1), deposit);
+
+    assert_ok!(_confirm_default_call());
+    assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+  });
+}
+
+#[test]
+fn propose_call_should_fail_when_call_is_not_related_to_space() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+
+    assert_noop!(_propose_call(
+      None,
+      None,
+      Some(Call::Balances(pallet_balances::Call::transfer(ACCOUNT3, 10)))
+    ), Error::<Test>::CallIsNotRelatedToSpace);
+  });
+}
+
+#[test]
+fn propose_call_should_dispatch_call_at_once_when_threshold_is_one() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_space_owners(None, None, None, Some(1)));
+    fund_space_account(1);
+
+    assert_ok!(_propose_default_call());
+
+    assert_eq!(Balances::free_balance(ACCOUNT4), 10);
+    assert!(MultiOwnership::proposal_by_id(1).is_none());
+  });
+}
+
+#[test]
+fn propose_call_should_fail_when_call_is_not_allowed() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+
+    assert_noop!(_propose_call(
+      None,
+      None,
+      Some(Call::System(frame_system::Call::remark(vec![])))
+    ), Error::<Test>::CallIsNotAllowed);
+  });
+}
+
+#[test]
+fn propose_call_should_fail_when_account_is_not_a_space_owner() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+
+    assert_noop!(
+      _propose_call(Some(Origin::signed(ACCOUNT3)), None, None),
+      Error::<Test>::NotASpaceOwner
+    );
+  });
+}
+
+#[test]
+fn confirm_call_should_fail_when_already_confirmed() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    assert_ok!(_propose_default_call());
+
+    assert_noop!(
+      _confirm_call(Some(Origin::signed(ACCOUNT1)), None, None),
+      Error::<Test>::ProposalAlreadyConfirmed
+    );
+  });
+}
+
+#[test]
+fn confirm_call_should_fail_when_max_weight_is_too_low() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    assert_ok!(_propose_default_call());
+
+    assert_noop!(_confirm_call(None, None, Some(0)), Error::<Test>::MaxWeightTooLow);
+  });
+}
+
+#[test]
+fn cancel_call_should_work() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    assert_ok!(_propose_default_call());
+
+    assert_ok!(_cancel_call(None, None));
+
+    assert!(MultiOwnership::proposal_by_id(1).is_none());
+    assert!(MultiOwnership::proposal_ids_expiring_at(1 + BlocksToLive::get()).is_empty());
+    assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+    assert_noop!(_confirm_default_call(), Error::<Test>::ProposalNotFound);
+  });
+}
+
+#[test]
+fn cancel_call_should_fail_when_account_is_not_a_creator() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    assert_ok!(_propose_default_call());
+
+    assert_noop!(
+      _cancel_call(Some(Origin::signed(ACCOUNT2)), None),
+      Error::<Test>::NotAProposalCreator
+    );
+  });
+}
+
+#[test]
+fn delete_expired_proposals_should_remove_expired_call_proposals() {
+  ExtBuilder::build().execute_with(|| {
+    assert_ok!(_create_default_space_owners());
+    assert_ok!(_propose_default_call());
+
+    let expires_at = MultiOwnership::proposal_by_id(1).unwrap().expires_at;
+    assert_eq!(MultiOwnership::proposal_ids_expiring_at(expires_at), vec![1]);
+
+    MultiOwnership::delete_expired_proposals(expires_at - 1);
+    assert!(MultiOwnership::proposal_by_id(1).is_some());
+
+    MultiOwnership::delete_expired_proposals(expires_at);
+
+    assert!(MultiOwnership::proposal_by_id(1).is_none());
+    assert!(MultiOwnership::pending_proposal_ids_by_space_id(1).is_empty());
+    assert!(MultiOwnership::proposal_ids_expiring_at(expires_at).is_empty());
+    assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+  });
+}
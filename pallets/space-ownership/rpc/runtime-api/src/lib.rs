@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_space_ownership::rpc::FlatPendingSpaceOwnershipTransfer;
+
+sp_api::decl_runtime_apis! {
+    pub trait SpaceOwnershipApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_pending_transfers_to_account(
+            account: AccountId,
+        ) -> Vec<FlatPendingSpaceOwnershipTransfer<AccountId, BlockNumber>>;
+    }
+}
@@ -3,16 +3,19 @@
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     ensure,
-    dispatch::DispatchResult,
-    traits::Get
+    dispatch::{DispatchError, DispatchResult},
+    traits::Get,
+    weights::Weight,
 };
 use sp_std::prelude::*;
 use frame_system::{self as system, ensure_signed};
 
-use df_traits::moderation::IsAccountBlocked;
-use pallet_spaces::{Module as Spaces, SpaceById, SpaceIdsByOwner};
+use df_traits::{SpaceOwnerManager, moderation::IsAccountBlocked};
+use pallet_spaces::Module as Spaces;
 use pallet_utils::{Error as UtilsError, SpaceId, remove_from_vec};
 
+pub mod rpc;
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
@@ -20,6 +23,9 @@ pub trait Trait: system::Trait
 {
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Max number of pending ownership transfers that can expire at the same block.
+    type MaxTransfersExpiringPerBlock: Get<u16>;
 }
 
 decl_error! {
@@ -34,6 +40,12 @@ decl_error! {
     NotAllowedToAcceptOwnershipTransfer,
     /// Account is not allowed to reject ownership transfer.
     NotAllowedToRejectOwnershipTransfer,
+    /// Only the current space owner can cancel ownership transfer.
+    NotAllowedToCancelOwnershipTransfer,
+    /// Ownership transfer should expire at a future block.
+    TransferExpiryInPast,
+    /// Too many pending ownership transfers already expire at this block.
+    TooManyTransfersExpiringAtBlock,
   }
 }
 
@@ -42,6 +54,14 @@ decl_storage! {
     trait Store for Module<T: Trait> as SpaceOwnershipModule {
         pub PendingSpaceOwner get(fn pending_space_owner):
             map hasher(twox_64_concat) SpaceId => Option<T::AccountId>;
+
+        /// A block at which a pending ownership transfer of a space expires.
+        pub PendingTransferExpiresAt get(fn pending_transfer_expires_at):
+            map hasher(twox_64_concat) SpaceId => Option<T::BlockNumber>;
+
+        /// Spaces whose pending ownership transfers expire at a given block.
+        pub PendingTransfersExpiringAt get(fn pending_transfers_expiring_at):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<SpaceId>;
     }
 }
 
@@ -52,6 +72,8 @@ decl_event!(
         SpaceOwnershipTransferCreated(/* current owner */ AccountId, SpaceId, /* new owner */ AccountId),
         SpaceOwnershipTransferAccepted(AccountId, SpaceId),
         SpaceOwnershipTransferRejected(AccountId, SpaceId),
+        SpaceOwnershipTransferCanceled(AccountId, SpaceId),
+        SpaceOwnershipTransferExpired(SpaceId),
     }
 );
 
@@ -59,14 +81,26 @@ decl_event!(
 decl_module! {
   pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 
+    /// Max number of pending ownership transfers that can expire at the same block.
+    const MaxTransfersExpiringPerBlock: u16 = T::MaxTransfersExpiringPerBlock::get();
+
     // Initializing errors
     type Error = Error<T>;
 
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
-    pub fn transfer_space_ownership(origin, space_id: SpaceId, transfer_to: T::AccountId) -> DispatchResult {
+    fn on_initialize(block_number: T::BlockNumber) -> Weight {
+      Self::remove_expired_transfers(block_number)
+    }
+
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 4)]
+    pub fn transfer_space_ownership(
+      origin,
+      space_id: SpaceId,
+      transfer_to: T::AccountId,
+      expires_at: Option<T::BlockNumber>
+    ) -> DispatchResult {
       let who = ensure_signed(origin)?;
 
       let space = Spaces::<T>::require_space(space_id)?;
@@ -75,13 +109,28 @@ decl_module! {
       ensure!(who != transfer_to, Error::<T>::CannotTranferToCurrentOwner);
       ensure!(T::IsAccountBlocked::is_allowed_account(transfer_to.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
 
+      if let Some(expires_at) = expires_at {
+        ensure!(expires_at > <system::Module<T>>::block_number(), Error::<T>::TransferExpiryInPast);
+        ensure!(
+          Self::pending_transfers_expiring_at(expires_at).len() < T::MaxTransfersExpiringPerBlock::get() as usize,
+          Error::<T>::TooManyTransfersExpiringAtBlock
+        );
+      }
+
+      // A new transfer overwrites the previous one, so the previous expiry is not relevant anymore.
+      Self::remove_pending_transfer(space_id);
+
       <PendingSpaceOwner<T>>::insert(space_id, transfer_to.clone());
+      if let Some(expires_at) = expires_at {
+        <PendingTransferExpiresAt<T>>::insert(space_id, expires_at);
+        <PendingTransfersExpiringAt<T>>::mutate(expires_at, |space_ids| space_ids.push(space_id));
+      }
 
       Self::deposit_event(RawEvent::SpaceOwnershipTransferCreated(who, space_id, transfer_to));
       Ok(())
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 2)]
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 5)]
     pub fn accept_pending_ownership(origin, space_id: SpaceId) -> DispatchResult {
       let new_owner = ensure_signed(origin)?;
 
@@ -92,19 +141,9 @@ decl_module! {
       ensure!(new_owner == transfer_to, Error::<T>::NotAllowedToAcceptOwnershipTransfer);
 
       // Here we know that the origin is eligible to become a new owner of this space.
-      <PendingSpaceOwner<T>>::remove(space_id);
+      Self::remove_pending_transfer(space_id);
 
-      Spaces::maybe_transfer_handle_deposit_to_new_space_owner(&space, &new_owner)?;
-
-      let old_owner = space.owner;
-      space.owner = new_owner.clone();
-      <SpaceById<T>>::insert(space_id, space);
-
-      // Remove space id from the list of spaces by old owner
-      <SpaceIdsByOwner<T>>::mutate(old_owner.clone(), |space_ids| remove_from_vec(space_ids, space_id));
-
-      // Add space id to the list of spaces by new owner
-      <SpaceIdsByOwner<T>>::mutate(new_owner.clone(), |ids| ids.push(space_id));
+      Spaces::change_space_owner(&mut space, new_owner.clone())?;
 
       // TODO add a new owner as a space follower? See T::BeforeSpaceCreated::before_space_created(new_owner.clone(), space)?;
 
@@ -112,7 +151,7 @@ decl_module! {
       Ok(())
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 3)]
     pub fn reject_pending_ownership(origin, space_id: SpaceId) -> DispatchResult {
       let who = ensure_signed(origin)?;
 
@@ -120,10 +159,68 @@ decl_module! {
       let transfer_to = Self::pending_space_owner(space_id).ok_or(Error::<T>::NoPendingTransferOnSpace)?;
       ensure!(who == transfer_to || who == space.owner, Error::<T>::NotAllowedToRejectOwnershipTransfer);
 
-      <PendingSpaceOwner<T>>::remove(space_id);
+      Self::remove_pending_transfer(space_id);
 
       Self::deposit_event(RawEvent::SpaceOwnershipTransferRejected(who, space_id));
       Ok(())
     }
+
+    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 3)]
+    pub fn cancel_pending_ownership(origin, space_id: SpaceId) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      let space = Spaces::<T>::require_space(space_id)?;
+      ensure!(<PendingSpaceOwner<T>>::contains_key(space_id), Error::<T>::NoPendingTransferOnSpace);
+      ensure!(space.is_owner(&who), Error::<T>::NotAllowedToCancelOwnershipTransfer);
+
+      Self::remove_pending_transfer(space_id);
+
+      Self::deposit_event(RawEvent::SpaceOwnershipTransferCanceled(who, space_id));
+      Ok(())
+    }
   }
 }
+
+impl<T: Trait> Module<T> {
+    /// Remove a pending ownership transfer of a space together with its expiry.
+    fn remove_pending_transfer(space_id: SpaceId) {
+        <PendingSpaceOwner<T>>::remove(space_id);
+
+        if let Some(expires_at) = <PendingTransferExpiresAt<T>>::take(space_id) {
+            <PendingTransfersExpiringAt<T>>::mutate(expires_at, |space_ids| remove_from_vec(space_ids, space_id));
+        }
+    }
+
+    /// Remove pending ownership transfers that expire at `block_number`.
+    fn remove_expired_transfers(block_number: T::BlockNumber) -> Weight {
+        let expired_space_ids = <PendingTransfersExpiringAt<T>>::take(block_number);
+        let expired_count = expired_space_ids.len() as Weight;
+
+        for space_id in expired_space_ids {
+            <PendingSpaceOwner<T>>::remove(space_id);
+            <PendingTransferExpiresAt<T>>::remove(space_id);
+            Self::deposit_event(RawEvent::SpaceOwnershipTransferExpired(space_id));
+        }
+
+        T::DbWeight::get().reads_writes(1, expired_count.saturating_mul(2).saturating_add(1))
+    }
+}
+
+impl<T: Trait> SpaceOwnerManager for Module<T> {
+    type AccountId = T::AccountId;
+
+    fn get_space_owner(space_id: SpaceId) -> Result<Self::AccountId, DispatchError> {
+        Ok(Spaces::<T>::require_space(space_id)?.owner)
+    }
+
+    /// A pending ownership transfer is removed, as it was created by the previous owner.
+    fn set_space_owner(space_id: SpaceId, new_owner: Self::AccountId) -> DispatchResult {
+        let mut space = Spaces::<T>::require_space(space_id)?;
+        Self::remove_pending_transfer(space_id);
+        Spaces::change_space_owner(&mut space, new_owner)
+    }
+
+    fn space_account_id(space_id: SpaceId) -> Self::AccountId {
+        Spaces::<T>::space_representative_account_id(space_id)
+    }
+}
@@ -0,0 +1,43 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use frame_support::storage::IterableStorageMap;
+use sp_std::prelude::*;
+
+use pallet_spaces::Module as Spaces;
+use pallet_utils::{SpaceId, rpc::ShouldSkip};
+
+use crate::{Module, PendingSpaceOwner, Trait};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPendingSpaceOwnershipTransfer<AccountId, BlockNumber> {
+    pub space_id: SpaceId,
+    pub current_owner: AccountId,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<T: Trait> Module<T> {
+    /// Get all pending ownership transfers of spaces to `account`.
+    pub fn get_pending_transfers_to_account(
+        account: T::AccountId,
+    ) -> Vec<FlatPendingSpaceOwnershipTransfer<T::AccountId, T::BlockNumber>> {
+        let mut transfers: Vec<_> = PendingSpaceOwner::<T>::iter()
+            .filter(|(_, transfer_to)| *transfer_to == account)
+            .filter_map(|(space_id, _)| {
+                let space = Spaces::<T>::require_space(space_id).ok()?;
+                Some(FlatPendingSpaceOwnershipTransfer {
+                    space_id,
+                    current_owner: space.owner,
+                    expires_at: Self::pending_transfer_expires_at(space_id),
+                })
+            })
+            .collect();
+
+        transfers.sort_by_key(|transfer| transfer.space_id);
+        transfers
+    }
+}
@@ -7,6 +7,7 @@ use pallet_spaces::rpc::FlatSpace;
 use pallet_utils::SpaceId;
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait SpacesApi<AccountId, BlockNumber> where
         AccountId: Codec,
         BlockNumber: Codec
@@ -28,5 +29,7 @@ sp_api::decl_runtime_apis! {
         fn get_space_by_handle(handle: Vec<u8>) -> Option<FlatSpace<AccountId, BlockNumber>>;
 
         fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId>;
+
+        fn get_top_spaces(offset: u64, limit: u16) -> Vec<FlatSpace<AccountId, BlockNumber>>;
     }
 }
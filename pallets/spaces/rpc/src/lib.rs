@@ -73,6 +73,14 @@ pub trait SpacesApi<BlockHash, AccountId, BlockNumber> {
 
     #[rpc(name = "spaces_nextSpaceId")]
     fn get_next_space_id(&self, at: Option<BlockHash>) -> Result<SpaceId>;
+
+    #[rpc(name = "spaces_getTopSpaces")]
+    fn get_top_spaces(
+        &self,
+        at: Option<BlockHash>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>>;
 }
 
 pub struct Spaces<C, M> {
@@ -192,4 +200,17 @@ where
         let runtime_api_result = api.get_next_space_id(&at);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_top_spaces(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_top_spaces(&at, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -23,20 +23,23 @@
 
 use codec::{Decode, Encode};
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
-    dispatch::{DispatchError, DispatchResult},
-    traits::{Get, Currency, ExistenceRequirement, ReservableCurrency},
+    decl_error, decl_event, decl_module, decl_storage, ensure, transactional, Parameter,
+    dispatch::{DispatchError, DispatchResult, PostDispatchInfo},
+    traits::{Get, Currency, ExistenceRequirement, ReservableCurrency, Filter, IsType, OriginTrait},
+    weights::GetDispatchInfo,
 };
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, traits::{Dispatchable, Hash}};
 use sp_std::prelude::*;
 use frame_system::{self as system, ensure_signed};
 
 use df_traits::{
     SpaceForRoles, SpaceForRolesProvider, PermissionChecker, SpaceFollowsProvider,
+    SpaceCoOwnersProvider,
     moderation::{IsAccountBlocked, IsContentBlocked},
 };
+pub use df_traits::{CallSpaceResolver, CallRawContentDetector};
 use pallet_permissions::{Module as Permissions, SpacePermission, SpacePermissions, SpacePermissionsContext};
-use pallet_utils::{Module as Utils, Error as UtilsError, SpaceId, WhoAndWhen, Content};
+use pallet_utils::{Module as Utils, Error as UtilsError, SpaceId, User, WhoAndWhen, Content, remove_from_vec};
 
 pub mod rpc;
 
@@ -110,6 +113,10 @@ pub trait Trait: system::Trait
 
     type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
 
+    /// Co-owners of multi-owned spaces are treated as space owners when permissions are checked,
+    /// except for owner-only permissions.
+    type SpaceCoOwners: SpaceCoOwnersProvider<Self::AccountId>;
+
     type BeforeSpaceCreated: BeforeSpaceCreated<Self>;
 
     type AfterSpaceUpdated: AfterSpaceUpdated<Self>;
@@ -119,6 +126,21 @@ pub trait Trait: system::Trait
     type IsContentBlocked: IsContentBlocked;
 
     type HandleDeposit: Get<BalanceOf<Self>>;
+
+    /// The overarching call type.
+    type Call: Parameter
+        + Dispatchable<Origin=Self::Origin, PostInfo=PostDispatchInfo>
+        + GetDispatchInfo + From<frame_system::Call<Self>>
+        + IsType<<Self as frame_system::Trait>::Call>;
+
+    /// Filters calls that can be dispatched on behalf of a space with `as_space`.
+    type SpaceActionsFilter: Filter<<Self as Trait>::Call>;
+
+    /// Finds a space within which a call acts to decide what `Represent*` permission is required.
+    type CallSpaceResolver: CallSpaceResolver<<Self as Trait>::Call>;
+
+    /// Detects calls with `Raw` content, which cannot be dispatched with `as_space`.
+    type CallRawContentDetector: CallRawContentDetector<<Self as Trait>::Call>;
 }
 
 decl_error! {
@@ -137,6 +159,12 @@ decl_error! {
     NoPermissionToCreateSubspaces,
     /// Space is at root level, no `parent_id` specified.
     SpaceIsAtRoot,
+    /// User has no permission to act on behalf of this space.
+    NoPermissionToRepresentSpace,
+    /// This call cannot be dispatched on behalf of a space.
+    CallIsNotAllowedForSpace,
+    /// `Raw` content cannot be created on behalf of a space, as its account cannot reserve a deposit.
+    RawContentIsNotSupportedForSpace,
   }
 }
 
@@ -165,6 +193,20 @@ decl_storage! {
 
         pub SpaceIdsByOwner get(fn space_ids_by_owner):
             map hasher(twox_64_concat) T::AccountId => Vec<SpaceId>;
+
+        /// A space that is represented by a given account, if this account has ever acted
+        /// on behalf of a space. Lets clients show that content was authored by a space.
+        pub SpaceIdByRepresentativeAccount get(fn space_id_by_representative_account):
+            map hasher(twox_64_concat) T::AccountId => Option<SpaceId>;
+
+        /// A space on behalf of which `as_space` is dispatching a call at the moment.
+        /// It is not kept between extrinsics.
+        pub RepresentedSpaceId get(fn represented_space_id): Option<SpaceId>;
+
+        /// A deposit reserved for the `Raw` content of a space and an account it is reserved from:
+        /// the space owner or an editor that set this content.
+        pub ContentDepositBySpaceId get(fn content_deposit_by_space_id):
+            map hasher(twox_64_concat) SpaceId => Option<(T::AccountId, pallet_utils::BalanceOf<T>)>;
     }
     add_extra_genesis {
       config(endowed_account): T::AccountId;
@@ -178,6 +220,8 @@ decl_event!(
         SpaceCreated(AccountId, SpaceId),
         SpaceUpdated(AccountId, SpaceId),
         SpaceDeleted(AccountId, SpaceId),
+        /// An account dispatched a call on behalf of a space. [who, space_id, result]
+        ActedAsSpace(AccountId, SpaceId, DispatchResult),
     }
 );
 
@@ -193,7 +237,8 @@ decl_module! {
     // Initializing events
     fn deposit_event() = default;
 
-    #[weight = 500_000 + T::DbWeight::get().reads_writes(4, 4)]
+    #[weight = 500_000 + T::DbWeight::get().reads_writes(4, 5)]
+    #[transactional]
     pub fn create_space(
       origin,
       parent_id_opt: Option<SpaceId>,
@@ -233,6 +278,9 @@ decl_module! {
 
       T::BeforeSpaceCreated::before_space_created(owner.clone(), new_space)?;
 
+      let content_deposit = Utils::<T>::reserve_content_deposit(&owner, &new_space.content)?;
+      <ContentDepositBySpaceId<T>>::insert(space_id, (owner.clone(), content_deposit));
+
       <SpaceById<T>>::insert(space_id, new_space);
       <SpaceIdsByOwner<T>>::mutate(owner.clone(), |ids| ids.push(space_id));
       NextSpaceId::mutate(|n| { *n += 1; });
@@ -241,7 +289,7 @@ decl_module! {
       Ok(())
     }
 
-    #[weight = 500_000 + T::DbWeight::get().reads_writes(2, 3)]
+    #[weight = 500_000 + T::DbWeight::get().reads_writes(3, 4)]
     pub fn update_space(origin, space_id: SpaceId, update: SpaceUpdate) -> DispatchResult {
       let owner = ensure_signed(origin)?;
 
@@ -298,6 +346,9 @@ decl_module! {
             ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), parent_id), UtilsError::<T>::ContentIsBlocked);
           }
 
+          // A deposit for raw content is reserved from the editor.
+          Utils::<T>::ensure_can_reserve_content_deposit(&owner, &content)?;
+
           old_data.content = Some(space.content);
           space.content = content;
           is_update_applied = true;
@@ -335,6 +386,12 @@ decl_module! {
 
       // Update this space only if at least one field should be updated:
       if is_update_applied {
+        if old_data.content.is_some() {
+          let old_deposit = Self::content_deposit_by_space_id(space_id);
+          let new_deposit = Utils::<T>::replace_content_deposit_of_depositor(&owner, old_deposit, &space.content)?;
+          <ContentDepositBySpaceId<T>>::insert(space_id, new_deposit);
+        }
+
         space.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
 
         <SpaceById<T>>::insert(space_id, space.clone());
@@ -344,6 +401,62 @@ decl_module! {
       }
       Ok(())
     }
+
+    /// Dispatch a `call` on behalf of a space, e.g. to create a post, a reaction or a follow
+    /// authored by the space. Requires `RepresentSpaceInternally` permission if the call acts
+    /// within the same space or `RepresentSpaceExternally` permission otherwise.
+    /// Calls with `Raw` content are not supported, as nobody funds the space account.
+    #[weight = {
+      let dispatch_info = call.get_dispatch_info();
+      (
+        T::DbWeight::get().reads_writes(5, 3)
+          .saturating_add(dispatch_info.weight)
+          .saturating_add(10_000),
+        dispatch_info.class,
+      )
+    }]
+    pub fn as_space(origin, space_id: SpaceId, call: Box<<T as Trait>::Call>) -> DispatchResult {
+      let who = ensure_signed(origin)?;
+
+      ensure!(T::SpaceActionsFilter::filter(&call), Error::<T>::CallIsNotAllowedForSpace);
+      ensure!(!T::CallRawContentDetector::has_raw_content(&call), Error::<T>::RawContentIsNotSupportedForSpace);
+
+      let space = Self::require_space(space_id)?;
+      let space_account = Self::space_representative_account_id(space_id);
+
+      ensure!(T::IsAccountBlocked::is_allowed_account(who.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+
+      let permission =
+        if T::CallSpaceResolver::resolve_space_id(&call) == Some(space_id) {
+          SpacePermission::RepresentSpaceInternally
+        } else {
+          SpacePermission::RepresentSpaceExternally
+        };
+
+      Self::ensure_account_has_space_permission(
+        who.clone(),
+        &space,
+        permission,
+        Error::<T>::NoPermissionToRepresentSpace.into()
+      )?;
+
+      if !<SpaceIdByRepresentativeAccount<T>>::contains_key(&space_account) {
+        <SpaceIdByRepresentativeAccount<T>>::insert(&space_account, space_id);
+      }
+
+      let mut origin: T::Origin = frame_system::RawOrigin::Signed(space_account).into();
+      origin.add_filter(move |c: &<T as frame_system::Trait>::Call| {
+        let c = <T as Trait>::Call::from_ref(c);
+        T::SpaceActionsFilter::filter(c)
+      });
+
+      RepresentedSpaceId::put(space_id);
+      let result = call.dispatch(origin);
+      RepresentedSpaceId::kill();
+
+      Self::deposit_event(RawEvent::ActedAsSpace(who, space_id, result.map(|_| ()).map_err(|e| e.error)));
+      Ok(())
+    }
   }
 }
 
@@ -464,9 +577,30 @@ impl<T: Trait> Module<T> {
         permission: SpacePermission,
         error: DispatchError,
     ) -> DispatchResult {
-        let is_owner = space.is_owner(&account);
         let is_follower = space.is_follower(&account);
 
+        // An account that acts on behalf of a space (see `as_space`) has only the permissions
+        // of the roles granted to that space, not the rights of the space owner, even if
+        // this space is owned by its own account (i.e. by multiple accounts).
+        if let Some(represented_space_id) = Self::represented_space_id() {
+            if account == Self::space_representative_account_id(represented_space_id) {
+                return T::Roles::ensure_user_has_space_permission(
+                    User::Space(represented_space_id),
+                    SpacePermissionsContext {
+                        space_id: space.id,
+                        is_space_owner: false,
+                        is_space_follower: is_follower,
+                        space_perms: space.permissions.clone(),
+                    },
+                    permission,
+                    error,
+                );
+            }
+        }
+
+        let is_owner = space.is_owner(&account)
+            || (!permission.is_owner_only() && T::SpaceCoOwners::is_space_co_owner(&account, space.id));
+
         let ctx = SpacePermissionsContext {
             space_id: space.id,
             is_space_owner: is_owner,
@@ -482,6 +616,14 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    /// An account on behalf of which the calls of `as_space` are dispatched.
+    /// It also owns a space that is owned by multiple accounts.
+    /// It is derived from the space id, so nobody knows its private key.
+    pub fn space_representative_account_id(space_id: SpaceId) -> T::AccountId {
+        let entropy = T::Hashing::hash_of(&(b"space/representative", space_id));
+        T::AccountId::decode(&mut entropy.as_ref()).unwrap_or_default()
+    }
+
     pub fn try_move_space_to_root(space_id: SpaceId) -> DispatchResult {
         let mut space = Self::require_space(space_id)?;
         space.parent_id = None;
@@ -543,6 +685,28 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Set a new owner of a space, move handle and content deposits to a new owner
+    /// and update the lists of spaces by owner.
+    /// A content deposit is moved only if it was reserved from the old owner.
+    #[transactional]
+    pub fn change_space_owner(space: &mut Space<T>, new_owner: T::AccountId) -> DispatchResult {
+        Self::maybe_transfer_handle_deposit_to_new_space_owner(space, &new_owner)?;
+        if let Some((depositor, deposit)) = Self::content_deposit_by_space_id(space.id) {
+            if depositor == space.owner {
+                Utils::<T>::transfer_content_deposit(&depositor, &new_owner, deposit)?;
+                <ContentDepositBySpaceId<T>>::insert(space.id, (new_owner.clone(), deposit));
+            }
+        }
+
+        let old_owner = sp_std::mem::replace(&mut space.owner, new_owner.clone());
+        <SpaceById<T>>::insert(space.id, space.clone());
+
+        <SpaceIdsByOwner<T>>::mutate(old_owner, |space_ids| remove_from_vec(space_ids, space.id));
+        <SpaceIdsByOwner<T>>::mutate(new_owner, |space_ids| space_ids.push(space.id));
+
+        Ok(())
+    }
+
     fn reserve_handle(
         space: &Space<T>,
         handle: Vec<u8>
@@ -611,6 +775,10 @@ impl<T: Trait> SpaceForRolesProvider for Module<T> {
             permissions: space.permissions,
         })
     }
+
+    fn is_space_co_owner(account: &Self::AccountId, space_id: SpaceId) -> bool {
+        T::SpaceCoOwners::is_space_co_owner(account, space_id)
+    }
 }
 
 pub trait BeforeSpaceCreated<T: Trait> {
@@ -7,6 +7,9 @@ use pallet_utils::{bool_to_option, SpaceId, rpc::{FlatContent, FlatWhoAndWhen, S
 
 use crate::{Module, Space, Trait, FIRST_SPACE_ID};
 
+/// Max number of the latest spaces ranked by `get_top_spaces`.
+pub const MAX_SPACES_TO_RANK: u64 = 10_000;
+
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -142,4 +145,24 @@ impl<T: Trait> Module<T> {
     pub fn get_next_space_id() -> SpaceId {
         Self::next_space_id()
     }
+
+    /// Get a page of public spaces sorted by score in a descending order and then by id.
+    /// Only the latest `MAX_SPACES_TO_RANK` spaces are ranked.
+    pub fn get_top_spaces(offset: u64, limit: u16) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
+        let next_space_id = Self::next_space_id();
+        let first_space_id = next_space_id.saturating_sub(MAX_SPACES_TO_RANK).max(FIRST_SPACE_ID);
+
+        let mut spaces: Vec<Space<T>> = (first_space_id..next_space_id)
+            .filter_map(|space_id| Self::require_space(space_id).ok())
+            .filter(|space| space.is_public())
+            .collect();
+
+        spaces.sort_by(|a, b| b.score.cmp(&a.score).then(a.id.cmp(&b.id)));
+
+        spaces.into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|space| space.into())
+            .collect()
+    }
 }
\ No newline at end of file
@@ -125,6 +125,10 @@ decl_storage! {
 		pub PlanIdsBySpace get(fn plan_ids_by_space):
 			map hasher(twox_64_concat) SpaceId => Vec<SubscriptionPlanId>;
 
+		/// A deposit reserved from the plan creator for the `Raw` content of a plan.
+		pub ContentDepositByPlanId get(fn content_deposit_by_plan_id):
+			map hasher(twox_64_concat) SubscriptionPlanId => BalanceOf<T>;
+
 		// Subscriptions:
 
 		pub NextSubscriptionId get(fn next_subscription_id): SubscriptionId = 1;
@@ -197,7 +201,7 @@ decl_module! {
 		/// It's possible to specify a price and time period (in blocks) for the plan.
 		/// Content could be an IPFS CID that points to an off-chain data such as
 		/// plan's title, description and cover image.
-		#[weight = T::DbWeight::get().reads_writes(3, 3) + 25_000]
+		#[weight = T::DbWeight::get().reads_writes(3, 4) + 25_000]
 		pub fn create_plan(
 			origin,
 			space_id: SpaceId,
@@ -218,6 +222,8 @@ decl_module! {
 			let space = Spaces::<T>::require_space(space_id)?;
 			Self::ensure_subscriptions_manager(sender.clone(), &space)?;
 
+			let content_deposit = Utils::<T>::reserve_content_deposit(&sender, &content)?;
+
 			let plan_id = Self::next_plan_id();
 			let subscription_plan = SubscriptionPlan::<T>::new(
 				plan_id,
@@ -231,6 +237,7 @@ decl_module! {
 
 			PlanById::<T>::insert(plan_id, subscription_plan);
 			PlanIdsBySpace::mutate(space_id, |ids| ids.push(plan_id));
+			ContentDepositByPlanId::<T>::insert(plan_id, content_deposit);
 			NextPlanId::mutate(|x| { *x += 1 });
 
 			Ok(())
@@ -280,6 +287,7 @@ decl_module! {
 			plan.is_active = false;
 			PlanById::<T>::insert(plan_id, plan.clone());
 			PlanIdsBySpace::mutate(plan.space_id, |ids| remove_from_vec(ids, plan_id));
+			Utils::<T>::unreserve_content_deposit(&plan.created.account, ContentDepositByPlanId::<T>::take(plan_id));
 
 			Ok(())
 		}
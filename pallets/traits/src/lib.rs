@@ -21,6 +21,56 @@ pub trait SpaceForRolesProvider {
   type AccountId;
 
   fn get_space(id: SpaceId) -> Result<SpaceForRoles<Self::AccountId>, DispatchError>;
+
+  /// Whether an account is one of co-owners of a space that is owned by multiple accounts.
+  fn is_space_co_owner(_account: &Self::AccountId, _space_id: SpaceId) -> bool {
+    false
+  }
+}
+
+/// Provides co-owners of spaces that are owned by multiple accounts.
+pub trait SpaceCoOwnersProvider<AccountId> {
+  fn is_space_co_owner(account: &AccountId, space_id: SpaceId) -> bool;
+}
+
+impl<AccountId> SpaceCoOwnersProvider<AccountId> for () {
+  fn is_space_co_owner(_account: &AccountId, _space_id: SpaceId) -> bool {
+    false
+  }
+}
+
+/// Operations on a space owner that are required by the space multi-ownership pallet.
+pub trait SpaceOwnerManager {
+  type AccountId;
+
+  fn get_space_owner(space_id: SpaceId) -> Result<Self::AccountId, DispatchError>;
+
+  fn set_space_owner(space_id: SpaceId, new_owner: Self::AccountId) -> DispatchResult;
+
+  /// An account derived from the space id that acts on behalf of a space.
+  fn space_account_id(space_id: SpaceId) -> Self::AccountId;
+}
+
+/// Resolves a space within which a call acts, e.g. a space of a post that is being reacted.
+pub trait CallSpaceResolver<Call> {
+  fn resolve_space_id(call: &Call) -> Option<SpaceId>;
+}
+
+impl<Call> CallSpaceResolver<Call> for () {
+  fn resolve_space_id(_call: &Call) -> Option<SpaceId> {
+    None
+  }
+}
+
+/// Detects if a call creates or updates `Raw` content, e.g. a post with `Content::Raw`.
+pub trait CallRawContentDetector<Call> {
+  fn has_raw_content(call: &Call) -> bool;
+}
+
+impl<Call> CallRawContentDetector<Call> for () {
+  fn has_raw_content(_call: &Call) -> bool {
+    false
+  }
 }
 
 pub trait SpaceFollowsProvider {
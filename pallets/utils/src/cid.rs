@@ -0,0 +1,156 @@
+//! Validation of IPFS content identifiers (CIDs) that works in `no_std`.
+//!
+//! Supported formats:
+//! - CIDv0: a base58btc-encoded sha2-256 multihash, e.g. `Qm...` (46 chars).
+//! - CIDv1: a multibase base32 (lowercase, no padding) string, e.g. `bafy...`.
+
+use super::*;
+
+/// CIDv0 is always a base58btc-encoded sha2-256 multihash of 46 chars.
+const CID_V0_LEN: usize = 46;
+const CID_V0_PREFIX: &[u8] = b"Qm";
+
+/// Multibase prefix of lowercase base32 without padding.
+const MULTIBASE_BASE32: u8 = b'b';
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Multicodecs of content that IPFS nodes can resolve.
+const CODEC_RAW: u64 = 0x55;
+const CODEC_DAG_PB: u64 = 0x70;
+const CODEC_DAG_CBOR: u64 = 0x71;
+const CODEC_DAG_JSON: u64 = 0x0129;
+
+/// Multihash function codes with the expected digest lengths.
+const HASH_SHA2_256: u64 = 0x12;
+const HASH_SHA2_512: u64 = 0x13;
+const HASH_BLAKE2B_256: u64 = 0xb220;
+
+impl<T: Trait> Module<T> {
+
+    /// Check that `cid` is a well-formed CIDv0 or CIDv1 with a supported codec and multihash.
+    pub fn ensure_valid_ipfs_cid(cid: &[u8]) -> DispatchResult {
+        match cid.first() {
+            Some(&b'Q') => Self::ensure_valid_cid_v0(cid),
+            Some(&MULTIBASE_BASE32) => Self::ensure_valid_cid_v1(&cid[1..]),
+            Some(_) => Err(Error::<T>::UnsupportedCidMultibase.into()),
+            None => Err(Error::<T>::InvalidIpfsCid.into()),
+        }
+    }
+
+    fn ensure_valid_cid_v0(cid: &[u8]) -> DispatchResult {
+        ensure!(cid.len() == CID_V0_LEN && cid.starts_with(CID_V0_PREFIX), Error::<T>::InvalidIpfsCid);
+
+        let multihash = decode_base58(cid).ok_or(Error::<T>::InvalidCidEncoding)?;
+
+        // CIDv0 can only be a sha2-256 multihash.
+        let (hash_code, rest) = read_varint(&multihash).ok_or(Error::<T>::InvalidIpfsCid)?;
+        ensure!(hash_code == HASH_SHA2_256, Error::<T>::UnsupportedCidMultihash);
+
+        Self::ensure_valid_multihash(rest, hash_code)
+    }
+
+    fn ensure_valid_cid_v1(encoded: &[u8]) -> DispatchResult {
+        let bytes = decode_base32(encoded).ok_or(Error::<T>::InvalidCidEncoding)?;
+
+        let (version, rest) = read_varint(&bytes).ok_or(Error::<T>::InvalidIpfsCid)?;
+        ensure!(version == 1, Error::<T>::UnsupportedCidVersion);
+
+        let (codec, multihash) = read_varint(rest).ok_or(Error::<T>::InvalidIpfsCid)?;
+        ensure!(
+            matches!(codec, CODEC_RAW | CODEC_DAG_PB | CODEC_DAG_CBOR | CODEC_DAG_JSON),
+            Error::<T>::UnsupportedCidCodec
+        );
+
+        let (hash_code, rest) = read_varint(multihash).ok_or(Error::<T>::InvalidIpfsCid)?;
+        Self::ensure_valid_multihash(rest, hash_code)
+    }
+
+    /// Check that a multihash digest (`rest` goes right after a hash function code)
+    /// has the length expected by the hash function and nothing follows the digest.
+    fn ensure_valid_multihash(rest: &[u8], hash_code: u64) -> DispatchResult {
+        let expected_len = match hash_code {
+            HASH_SHA2_256 | HASH_BLAKE2B_256 => 32,
+            HASH_SHA2_512 => 64,
+            _ => return Err(Error::<T>::UnsupportedCidMultihash.into()),
+        };
+
+        let (digest_len, digest) = read_varint(rest).ok_or(Error::<T>::InvalidIpfsCid)?;
+        ensure!(
+            digest_len == expected_len && digest.len() as u64 == expected_len,
+            Error::<T>::InvalidCidMultihashLength
+        );
+
+        Ok(())
+    }
+}
+
+/// Read an unsigned LEB128 varint (as used by multiformats) from the start of `bytes`.
+/// Return the value and the remaining bytes or `None` if a varint is truncated or too long.
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value: u64 = 0;
+
+    // Multiformats limit varints to 9 bytes.
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+
+    None
+}
+
+fn decode_base58(encoded: &[u8]) -> Option<Vec<u8>> {
+    // Big-endian bytes of a decoded number.
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+
+    for c in encoded {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Every leading '1' encodes a leading zero byte.
+    let leading_zeros = encoded.iter().take_while(|c| **c == BASE58_ALPHABET[0]).count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+
+    Some(decoded)
+}
+
+fn decode_base32(encoded: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded: Vec<u8> = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for c in encoded {
+        let value = BASE32_ALPHABET.iter().position(|a| a == c)? as u32;
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // Unpadded base32 can only leave less than 5 zero bits.
+    if bits >= 5 || buffer != 0 {
+        return None;
+    }
+
+    Some(decoded)
+}
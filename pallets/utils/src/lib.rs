@@ -5,15 +5,15 @@ use frame_support::{
     decl_error, decl_module, decl_storage, decl_event,
     dispatch::{DispatchError, DispatchResult}, ensure,
     traits::{
-        Currency, Get,
+        Currency, Get, ReservableCurrency, BalanceStatus,
         Imbalance, OnUnbalanced,
     },
 };
 use frame_system as system;
 
 #[cfg(feature = "std")]
-use serde::Deserialize;
-use sp_runtime::RuntimeDebug;
+use serde::{Deserialize, Serialize};
+use sp_runtime::{RuntimeDebug, traits::{Saturating, Zero}};
 use sp_std::{
     collections::btree_set::BTreeSet,
     prelude::*,
@@ -28,6 +28,8 @@ mod tests;
 
 pub mod rpc;
 
+mod cid;
+
 pub type SpaceId = u64;
 pub type PostId = u64;
 
@@ -49,6 +51,8 @@ impl<T: Trait> WhoAndWhen<T> {
 }
 
 #[derive(Encode, Decode, Ord, PartialOrd, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum User<AccountId> {
     Account(AccountId),
     Space(SpaceId),
@@ -78,9 +82,10 @@ impl<AccountId> User<AccountId> {
 pub enum Content {
     /// No content.
     None,
-    /// A raw vector of bytes.
+    /// A raw vector of bytes that is stored on chain.
+    /// Requires a deposit per byte (see `RawContentDepositPerByte`).
     Raw(Vec<u8>),
-    /// IPFS CID v0 of content.
+    /// IPFS CID (v0 or v1) of content.
     IPFS(Vec<u8>),
     /// Hypercore protocol (former DAT) id of content.
     Hyper(Vec<u8>),
@@ -115,9 +120,13 @@ impl Content {
     pub fn is_ipfs(&self) -> bool {
         matches!(self, Self::IPFS(_))
     }
+
+    pub fn is_raw(&self) -> bool {
+        matches!(self, Self::Raw(_))
+    }
 }
 
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+pub type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
 
 type NegativeImbalanceOf<T> = <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::NegativeImbalance;
 
@@ -127,13 +136,48 @@ pub trait Trait: system::Trait + pallet_timestamp::Trait
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     /// The currency mechanism.
-    type Currency: Currency<Self::AccountId>;
+    type Currency: ReservableCurrency<Self::AccountId>;
 
     /// Minimal length of space/profile handle
     type MinHandleLen: Get<u32>;
 
     /// Max length of a space handle.
     type MaxHandleLen: Get<u32>;
+
+    /// Max length of `Raw` content in bytes.
+    type MaxRawContentLen: Get<u32>;
+
+    /// A deposit per byte of `Raw` content that is reserved from its author.
+    type RawContentDepositPerByte: Get<BalanceOf<Self>>;
+
+    /// Validates content before it is saved by any pallet.
+    type ContentValidator: ContentValidator;
+}
+
+/// Validates content before it is saved on chain.
+/// A runtime can implement this trait to support new content types or to replace
+/// the default validation, and can delegate the rest to `DefaultContentValidator`.
+pub trait ContentValidator {
+    fn validate_content(content: &Content) -> DispatchResult;
+}
+
+/// Accepts `None`, `Raw` content up to `MaxRawContentLen` bytes and valid IPFS CIDs.
+/// `Hyper` content is not supported.
+pub struct DefaultContentValidator<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Trait> ContentValidator for DefaultContentValidator<T> {
+    fn validate_content(content: &Content) -> DispatchResult {
+        match content {
+            Content::None => Ok(()),
+            Content::Raw(bytes) => {
+                ensure!(!bytes.is_empty(), Error::<T>::ContentIsEmpty);
+                ensure!(bytes.len() <= T::MaxRawContentLen::get() as usize, Error::<T>::RawContentIsTooLong);
+                Ok(())
+            },
+            Content::IPFS(ipfs_cid) => Module::<T>::ensure_valid_ipfs_cid(ipfs_cid),
+            Content::Hyper(_) => Err(Error::<T>::HypercoreContentTypeNotSupported.into())
+        }
+    }
 }
 
 decl_storage! {
@@ -159,6 +203,10 @@ decl_module! {
 
         const MaxHandleLen: u32 = T::MaxHandleLen::get();
 
+        const MaxRawContentLen: u32 = T::MaxRawContentLen::get();
+
+        const RawContentDepositPerByte: BalanceOf<T> = T::RawContentDepositPerByte::get();
+
         // Initializing errors
         type Error = Error<T>;
 
@@ -177,7 +225,7 @@ decl_error! {
         PostIsBlocked,
         /// IPFS CID is invalid.
         InvalidIpfsCid,
-        /// `Raw` content type is not yet supported.
+        /// Deprecated: `Raw` content is supported now, see `RawContentIsTooLong`.
         RawContentTypeNotSupported,
         /// `Hyper` content type is not yet supported.
         HypercoreContentTypeNotSupported,
@@ -189,6 +237,24 @@ decl_error! {
         HandleContainsInvalidChars,
         /// Content type is `None`.
         ContentIsEmpty,
+        /// IPFS CID contains chars that are not allowed by its base encoding.
+        InvalidCidEncoding,
+        /// Only base58btc CIDv0 (`Qm...`) and base32 CIDv1 (`b...`) are supported.
+        UnsupportedCidMultibase,
+        /// Only CIDv0 and CIDv1 are supported.
+        UnsupportedCidVersion,
+        /// Multicodec of CID is not supported.
+        UnsupportedCidCodec,
+        /// Hash function of CID multihash is not supported.
+        UnsupportedCidMultihash,
+        /// Length of CID multihash digest does not match its hash function.
+        InvalidCidMultihashLength,
+        /// Account has not enough free balance to reserve a deposit for `Raw` content.
+        InsufficientBalanceForContentDeposit,
+        /// A reserved content deposit could not be fully moved to a new owner.
+        ContentDepositNotTransferred,
+        /// `Raw` content is longer than `MaxRawContentLen`.
+        RawContentIsTooLong,
     }
 }
 
@@ -225,19 +291,9 @@ pub fn bool_to_option(value: bool) -> Option<bool> {
 }
 
 impl<T: Trait> Module<T> {
+    /// Validate content with a validator configured in the runtime (see `ContentValidator`).
     pub fn is_valid_content(content: Content) -> DispatchResult {
-        match content {
-            Content::None => Ok(()),
-            Content::Raw(_) => Err(Error::<T>::RawContentTypeNotSupported.into()),
-            Content::IPFS(ipfs_cid) => {
-                let len = ipfs_cid.len();
-                // IPFS CID v0 is 46 bytes.
-                // IPFS CID v1 is 59 bytes.df-integration-tests/src/lib.rs:272:5
-                ensure!(len == 46 || len == 59, Error::<T>::InvalidIpfsCid);
-                Ok(())
-            },
-            Content::Hyper(_) => Err(Error::<T>::HypercoreContentTypeNotSupported.into())
-        }
+        T::ContentValidator::validate_content(&content)
     }
 
     pub fn convert_users_vec_to_btree_set(
@@ -254,7 +310,7 @@ impl<T: Trait> Module<T> {
 
     /// Check if a handle contains only valid chars: 0-9, a-z, _.
     /// An example of a valid handle: `good_handle_123`.
-    fn is_valid_handle_char(c: u8) -> bool {
+    pub fn is_valid_handle_char(c: u8) -> bool {
         matches!(c, b'0'..=b'9' | b'a'..=b'z' | b'_')
     }
 
@@ -288,6 +344,87 @@ impl<T: Trait> Module<T> {
         ensure!(content.is_some(), Error::<T>::ContentIsEmpty);
         Ok(())
     }
+
+    /// A deposit for storing a given content on chain.
+    /// Only `Raw` content requires a deposit, other types of content are stored off chain.
+    pub fn content_deposit(content: &Content) -> BalanceOf<T> {
+        match content {
+            Content::Raw(bytes) =>
+                T::RawContentDepositPerByte::get().saturating_mul((bytes.len() as u32).into()),
+            _ => Zero::zero(),
+        }
+    }
+
+    /// Ensure that `who` can reserve a deposit for a given content.
+    /// Use it to check a deposit before any changes to storage are made.
+    pub fn ensure_can_reserve_content_deposit(who: &T::AccountId, content: &Content) -> DispatchResult {
+        ensure!(
+            T::Currency::can_reserve(who, Self::content_deposit(content)),
+            Error::<T>::InsufficientBalanceForContentDeposit
+        );
+        Ok(())
+    }
+
+    /// Reserve a deposit for a given content and return the reserved amount.
+    /// Store this amount with the content to release or move exactly it later,
+    /// even if `RawContentDepositPerByte` changes in the meantime.
+    pub fn reserve_content_deposit(who: &T::AccountId, content: &Content) -> Result<BalanceOf<T>, DispatchError> {
+        let deposit = Self::content_deposit(content);
+        if !deposit.is_zero() {
+            T::Currency::reserve(who, deposit)?;
+        }
+        Ok(deposit)
+    }
+
+    pub fn unreserve_content_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+        if !deposit.is_zero() {
+            T::Currency::unreserve(who, deposit);
+        }
+    }
+
+    /// Reserve a deposit for a new content, then release a deposit of an old content.
+    /// Returns the deposit reserved for a new content.
+    pub fn replace_content_deposit(
+        who: &T::AccountId,
+        old_deposit: BalanceOf<T>,
+        new_content: &Content,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let new_deposit = Self::reserve_content_deposit(who, new_content)?;
+        Self::unreserve_content_deposit(who, old_deposit);
+        Ok(new_deposit)
+    }
+
+    /// Reserve a deposit for a new content from `who`, then release a deposit of an old content
+    /// from an account that reserved it. Use it when content can be updated not only by its owner.
+    /// Returns `who` together with the deposit reserved for a new content.
+    pub fn replace_content_deposit_of_depositor(
+        who: &T::AccountId,
+        old_deposit_opt: Option<(T::AccountId, BalanceOf<T>)>,
+        new_content: &Content,
+    ) -> Result<(T::AccountId, BalanceOf<T>), DispatchError> {
+        let new_deposit = Self::reserve_content_deposit(who, new_content)?;
+        if let Some((old_depositor, old_deposit)) = old_deposit_opt {
+            Self::unreserve_content_deposit(&old_depositor, old_deposit);
+        }
+        Ok((who.clone(), new_deposit))
+    }
+
+    /// Move a reserved content deposit from an old owner to a new owner.
+    pub fn transfer_content_deposit(
+        old_owner: &T::AccountId,
+        new_owner: &T::AccountId,
+        deposit: BalanceOf<T>,
+    ) -> DispatchResult {
+        if !deposit.is_zero() {
+            ensure!(
+                T::Currency::reserved_balance(old_owner) >= deposit,
+                Error::<T>::ContentDepositNotTransferred
+            );
+            let not_moved = T::Currency::repatriate_reserved(old_owner, new_owner, deposit, BalanceStatus::Reserved)?;
+            ensure!(not_moved.is_zero(), Error::<T>::ContentDepositNotTransferred);
+        }
+        Ok(())
+    }
 }
 
 impl<T: Trait> OnUnbalanced<NegativeImbalanceOf<T>> for Module<T> {
@@ -1,4 +1,4 @@
-use crate::{Module, Trait, User};
+use crate::{Module, Trait, User, DefaultContentValidator};
 
 use sp_core::H256;
 use sp_std::collections::btree_set::BTreeSet;
@@ -80,6 +80,8 @@ impl pallet_balances::Trait for Test {
 parameter_types! {
   pub const MinHandleLen: u32 = 5;
   pub const MaxHandleLen: u32 = 50;
+  pub const MaxRawContentLen: u32 = 64;
+  pub const RawContentDepositPerByte: u64 = 1;
 }
 
 impl Trait for Test {
@@ -87,10 +89,13 @@ impl Trait for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type MaxRawContentLen = MaxRawContentLen;
+    type RawContentDepositPerByte = RawContentDepositPerByte;
+    type ContentValidator = DefaultContentValidator<Test>;
 }
 
 type System = system::Module<Test>;
-type Balances = pallet_balances::Module<Test>;
+pub(crate) type Balances = pallet_balances::Module<Test>;
 type Utils = Module<Test>;
 
 pub type AccountId = u64;
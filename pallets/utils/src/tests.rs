@@ -1,4 +1,9 @@
-use crate::{mock::*, remove_from_vec, log_2};
+use crate::{mock::*, remove_from_vec, log_2, Content, Error, Module};
+
+use frame_support::{
+    assert_ok, assert_err, dispatch::DispatchResult,
+    traits::{Currency, Get, ReservableCurrency},
+};
 
 use sp_std::iter::FromIterator;
 
@@ -91,3 +96,115 @@ fn convert_users_vec_to_btree_set_should_work() {
         );
     });
 }
+
+fn _is_valid_ipfs_content(cid: &[u8]) -> DispatchResult {
+    Module::<Test>::is_valid_content(Content::IPFS(cid.to_vec()))
+}
+
+#[test]
+fn is_valid_content_should_accept_valid_cids() {
+    ExtBuilder::build().execute_with(|| {
+        // CIDv0 of a dag-pb sha2-256 multihash
+        assert_ok!(_is_valid_ipfs_content(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4"));
+
+        // CIDv1 of dag-cbor, raw and dag-pb with sha2-256 and blake2b-256 multihashes
+        assert_ok!(_is_valid_ipfs_content(b"bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e"));
+        assert_ok!(_is_valid_ipfs_content(b"bafkreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u"));
+        assert_ok!(_is_valid_ipfs_content(b"bafykbzaceb7fnmkxdbksqvffzrs6oq6snu7662nnxnmmlcrvwz3xzvakykkzm"));
+    });
+}
+
+#[test]
+fn is_valid_content_should_reject_malformed_cids() {
+    ExtBuilder::build().execute_with(|| {
+        assert_err!(_is_valid_ipfs_content(b""), Error::<Test>::InvalidIpfsCid);
+        assert_err!(_is_valid_ipfs_content(b"QmRAQB6DaazhR8"), Error::<Test>::InvalidIpfsCid);
+
+        // '0' is not in the base58btc alphabet
+        assert_err!(
+            _is_valid_ipfs_content(b"QmRAQB6YaCaidP37UdDnjFY5aQuiBrbqdyoW1CaDgwxkD0"),
+            Error::<Test>::InvalidCidEncoding
+        );
+        // Only lowercase base32 is supported
+        assert_err!(
+            _is_valid_ipfs_content(b"bafkreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3X4u"),
+            Error::<Test>::InvalidCidEncoding
+        );
+        // CIDv1 in base58btc multibase
+        assert_err!(
+            _is_valid_ipfs_content(b"zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7"),
+            Error::<Test>::UnsupportedCidMultibase
+        );
+    });
+}
+
+#[test]
+fn is_valid_content_should_reject_cids_with_unsupported_parts() {
+    ExtBuilder::build().execute_with(|| {
+        // CID version 2
+        assert_err!(
+            _is_valid_ipfs_content(b"bajkreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u"),
+            Error::<Test>::UnsupportedCidVersion
+        );
+        // `json` multicodec
+        assert_err!(
+            _is_valid_ipfs_content(b"bagaaieramjd2hlrwggnxhqwgt5auoyvtzng734maposb3tedwpxjmp3do7sq"),
+            Error::<Test>::UnsupportedCidCodec
+        );
+        // `identity` multihash
+        assert_err!(
+            _is_valid_ipfs_content(b"bafkqaidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x4u"),
+            Error::<Test>::UnsupportedCidMultihash
+        );
+        // sha2-256 multihash with a 31-byte digest
+        assert_err!(
+            _is_valid_ipfs_content(b"bafkreidci6r24nrrtnz4fru7ifdwfm6ljx67dad3uqo4za5t52ld6y3x"),
+            Error::<Test>::InvalidCidMultihashLength
+        );
+    });
+}
+
+#[test]
+fn is_valid_content_should_check_raw_content_length() {
+    ExtBuilder::build().execute_with(|| {
+        let max_len = MaxRawContentLen::get() as usize;
+
+        assert_ok!(Module::<Test>::is_valid_content(Content::Raw(vec![b'a'; max_len])));
+        assert_err!(
+            Module::<Test>::is_valid_content(Content::Raw(vec![b'a'; max_len + 1])),
+            Error::<Test>::RawContentIsTooLong
+        );
+        assert_err!(Module::<Test>::is_valid_content(Content::Raw(vec![])), Error::<Test>::ContentIsEmpty);
+    });
+}
+
+#[test]
+fn content_deposit_should_be_charged_only_for_raw_content() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Module::<Test>::content_deposit(&Content::Raw(vec![b'a'; 10])), 10);
+        assert_eq!(Module::<Test>::content_deposit(&Content::None), 0);
+        assert_eq!(
+            Module::<Test>::content_deposit(&Content::IPFS(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec())),
+            0
+        );
+    });
+}
+
+#[test]
+fn transfer_content_deposit_should_move_only_reserved_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+
+        assert_err!(
+            Module::<Test>::transfer_content_deposit(&1, &2, 10),
+            Error::<Test>::ContentDepositNotTransferred
+        );
+
+        assert_eq!(Module::<Test>::reserve_content_deposit(&1, &Content::Raw(vec![b'a'; 10])), Ok(10));
+        assert_ok!(Module::<Test>::transfer_content_deposit(&1, &2, 10));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 10);
+    });
+}
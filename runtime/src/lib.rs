@@ -15,7 +15,7 @@ use sp_std::{
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
     ApplyExtrinsicResult, generic, create_runtime_str, impl_opaque_keys, MultiSignature,
-    transaction_validity::{TransactionValidity, TransactionSource},
+    transaction_validity::{TransactionValidity, TransactionSource, TransactionPriority},
 };
 use sp_runtime::traits::{
     BlakeTwo256, Block as BlockT, Verify, IdentifyAccount, NumberFor, Saturating,
@@ -33,6 +33,7 @@ use sp_version::NativeVersion;
 pub use sp_runtime::BuildStorage;
 pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_balances::Call as BalancesCall;
+pub use pallet_scores::ScoringWeights;
 pub use sp_runtime::{Permill, Perbill};
 pub use frame_support::{
     construct_runtime, parameter_types, StorageValue,
@@ -44,16 +45,22 @@ pub use frame_support::{
 };
 use frame_system::EnsureRoot;
 
+use pallet_notifications::rpc::FlatNotification;
 use pallet_permissions::SpacePermission;
-use pallet_posts::rpc::{FlatPost, FlatPostKind, RepliesByPostId};
+use pallet_post_history::rpc::FlatPostHistoryRecord;
+use pallet_posts::{PostExtension, rpc::{FeedOptions, FlatPost, FlatPostKind, FlatPostMention, RepliesByPostId}};
+use pallet_profile_history::rpc::FlatProfileHistoryRecord;
 use pallet_profiles::rpc::FlatSocialAccount;
 use pallet_reactions::{
 	ReactionId,
 	ReactionKind,
 	rpc::FlatReaction,
 };
+use pallet_roles::{RoleId, rpc::FlatRole};
+use pallet_space_history::rpc::FlatSpaceHistoryRecord;
+use pallet_space_ownership::rpc::FlatPendingSpaceOwnershipTransfer;
 use pallet_spaces::rpc::FlatSpace;
-use pallet_utils::{SpaceId, PostId};
+use pallet_utils::{SpaceId, PostId, User};
 
 pub mod constants;
 use constants::{currency::*, time::*};
@@ -116,10 +123,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("subsocial"),
 	impl_name: create_runtime_str!("dappforce-subsocial"),
 	authoring_version: 0,
-	spec_version: 11,
+	spec_version: 14,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 4,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -312,6 +319,8 @@ impl pallet_utility::Trait for Runtime {
 parameter_types! {
   pub const MinHandleLen: u32 = 5;
   pub const MaxHandleLen: u32 = 50;
+  pub const MaxRawContentLen: u32 = 1_024;
+  pub const RawContentDepositPerByte: Balance = 10 * MILLICENTS;
 }
 
 impl pallet_utils::Trait for Runtime {
@@ -319,6 +328,9 @@ impl pallet_utils::Trait for Runtime {
 	type Currency = Balances;
 	type MinHandleLen = MinHandleLen;
 	type MaxHandleLen = MaxHandleLen;
+	type MaxRawContentLen = MaxRawContentLen;
+	type RawContentDepositPerByte = RawContentDepositPerByte;
+	type ContentValidator = pallet_utils::DefaultContentValidator<Runtime>;
 }
 
 use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -329,25 +341,55 @@ impl pallet_permissions::Trait for Runtime {
 
 parameter_types! {
   pub const MaxCommentDepth: u32 = 10;
+  pub const MaxTagsPerPost: u16 = 10;
+  pub const MaxTagLen: u32 = 50;
+  pub const MaxMentionsPerPost: u16 = 20;
+  pub const HotPointsDoublingBlocks: BlockNumber = 12 * HOURS;
+  pub const HotPostsWindowBlocks: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_posts::Trait for Runtime {
 	type Event = Event;
 	type MaxCommentDepth = MaxCommentDepth;
 	type PostScores = Scores;
+	type AfterPostCreated = Notifications;
 	type AfterPostUpdated = PostHistory;
 	type IsPostBlocked = ()/*Moderation*/;
+	type MaxTagsPerPost = MaxTagsPerPost;
+	type MaxTagLen = MaxTagLen;
+	type MaxMentionsPerPost = MaxMentionsPerPost;
+	type HotPointsDoublingBlocks = HotPointsDoublingBlocks;
+	type HotPostsWindowBlocks = HotPostsWindowBlocks;
+}
+
+parameter_types! {
+	pub const MaxNotificationsPerAccount: u16 = 200;
+}
+
+impl pallet_notifications::Trait for Runtime {
+	type Event = Event;
+	type MaxNotificationsPerAccount = MaxNotificationsPerAccount;
+}
+
+parameter_types! {
+	pub const MaxPostHistoryRecords: u16 = 50;
+}
+
+impl pallet_post_history::Trait for Runtime {
+	type MaxHistoryRecords = MaxPostHistoryRecords;
 }
 
 parameter_types! {}
 
-impl pallet_post_history::Trait for Runtime {}
+impl pallet_post_ownership::Trait for Runtime {
+	type Event = Event;
+}
 
 parameter_types! {}
 
 impl pallet_profile_follows::Trait for Runtime {
 	type Event = Event;
-	type BeforeAccountFollowed = Scores;
+	type BeforeAccountFollowed = (Scores, Notifications);
 	type BeforeAccountUnfollowed = Scores;
 }
 
@@ -358,58 +400,46 @@ impl pallet_profiles::Trait for Runtime {
 	type AfterProfileUpdated = ProfileHistory;
 }
 
-parameter_types! {}
+parameter_types! {
+	pub const MaxProfileHistoryRecords: u16 = 50;
+}
 
-impl pallet_profile_history::Trait for Runtime {}
+impl pallet_profile_history::Trait for Runtime {
+	type MaxHistoryRecords = MaxProfileHistoryRecords;
+}
 
 parameter_types! {}
 
 impl pallet_reactions::Trait for Runtime {
 	type Event = Event;
-	type PostReactionScores = Scores;
+	type PostReactionScores = (Scores, Notifications);
 }
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+  pub const MaxExpiredRolesToDeletePerBlock: u16 = 50;
+  pub const MaxRolesToClone: u16 = 50;
 }
 
 impl pallet_roles::Trait for Runtime {
 	type Event = Event;
 	type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+	type MaxExpiredRolesToDeletePerBlock = MaxExpiredRolesToDeletePerBlock;
+	type MaxRolesToClone = MaxRolesToClone;
 	type Spaces = Spaces;
 	type SpaceFollows = SpaceFollows;
 	type IsAccountBlocked = ()/*Moderation*/;
 	type IsContentBlocked = ()/*Moderation*/;
+	type AfterRoleGranted = Notifications;
 }
 
 parameter_types! {
-  pub const FollowSpaceActionWeight: i16 = 7;
-  pub const FollowAccountActionWeight: i16 = 3;
-
-  pub const SharePostActionWeight: i16 = 7;
-  pub const UpvotePostActionWeight: i16 = 5;
-  pub const DownvotePostActionWeight: i16 = -3;
-
-  pub const CreateCommentActionWeight: i16 = 5;
-  pub const ShareCommentActionWeight: i16 = 5;
-  pub const UpvoteCommentActionWeight: i16 = 4;
-  pub const DownvoteCommentActionWeight: i16 = -2;
+	pub const ReputationHalfLife: BlockNumber = 180 * DAYS;
 }
 
 impl pallet_scores::Trait for Runtime {
 	type Event = Event;
-
-	type FollowSpaceActionWeight = FollowSpaceActionWeight;
-	type FollowAccountActionWeight = FollowAccountActionWeight;
-
-	type SharePostActionWeight = SharePostActionWeight;
-	type UpvotePostActionWeight = UpvotePostActionWeight;
-	type DownvotePostActionWeight = DownvotePostActionWeight;
-
-	type CreateCommentActionWeight = CreateCommentActionWeight;
-	type ShareCommentActionWeight = ShareCommentActionWeight;
-	type UpvoteCommentActionWeight = UpvoteCommentActionWeight;
-	type DownvoteCommentActionWeight = DownvoteCommentActionWeight;
+	type ReputationHalfLife = ReputationHalfLife;
 }
 
 parameter_types! {}
@@ -420,10 +450,13 @@ impl pallet_space_follows::Trait for Runtime {
 	type BeforeSpaceUnfollowed = Scores;
 }
 
-parameter_types! {}
+parameter_types! {
+	pub const MaxTransfersExpiringPerBlock: u16 = 100;
+}
 
 impl pallet_space_ownership::Trait for Runtime {
 	type Event = Event;
+	type MaxTransfersExpiringPerBlock = MaxTransfersExpiringPerBlock;
 }
 
 parameter_types! {
@@ -435,16 +468,126 @@ impl pallet_spaces::Trait for Runtime {
 	type Currency = Balances;
 	type Roles = Roles;
 	type SpaceFollows = SpaceFollows;
+	type SpaceCoOwners = SpaceMultiOwnership;
 	type BeforeSpaceCreated = SpaceFollows;
 	type AfterSpaceUpdated = SpaceHistory;
 	type IsAccountBlocked = ()/*Moderation*/;
 	type IsContentBlocked = ()/*Moderation*/;
 	type HandleDeposit = HandleDeposit;
+	type Call = Call;
+	type SpaceActionsFilter = SpaceActionsFilter;
+	type CallSpaceResolver = SpaceOfCallResolver;
+	type CallRawContentDetector = RawContentOfCallDetector;
 }
 
-parameter_types! {}
+/// Calls that an account can dispatch on behalf of a space with `Spaces::as_space`.
+pub struct SpaceActionsFilter;
+impl Filter<Call> for SpaceActionsFilter {
+	fn filter(c: &Call) -> bool {
+		match *c {
+			Call::Posts(pallet_posts::Call::create_post(..)) => true,
+			Call::Posts(pallet_posts::Call::update_post(..)) => true,
+			Call::Reactions(..) => true,
+			Call::SpaceFollows(..) => true,
+			Call::ProfileFollows(..) => true,
+			_ => false,
+		}
+	}
+}
+
+pub struct SpaceOfCallResolver;
+impl pallet_spaces::CallSpaceResolver<Call> for SpaceOfCallResolver {
+	fn resolve_space_id(c: &Call) -> Option<SpaceId> {
+		let space_id_of_post = |post_id: &PostId| {
+			Posts::post_by_id(post_id).and_then(|post| post.try_get_space_id())
+		};
+		let space_id_of_role = |role_id: &RoleId| Roles::role_by_id(role_id).map(|role| role.space_id);
+
+		match c {
+			Call::Posts(pallet_posts::Call::create_post(space_id_opt, extension, ..)) => match extension {
+				PostExtension::Comment(comment) => space_id_of_post(&comment.root_post_id),
+				_ => *space_id_opt,
+			},
+			Call::Posts(pallet_posts::Call::update_post(post_id, _)) => space_id_of_post(post_id),
+			Call::Posts(pallet_posts::Call::move_post(post_id, _)) => space_id_of_post(post_id),
+			Call::PostOwnership(pallet_post_ownership::Call::transfer_post_ownership(post_id, _)) => space_id_of_post(post_id),
+			Call::Reactions(pallet_reactions::Call::create_post_reaction(post_id, _)) => space_id_of_post(post_id),
+			Call::Reactions(pallet_reactions::Call::update_post_reaction(post_id, ..)) => space_id_of_post(post_id),
+			Call::Reactions(pallet_reactions::Call::delete_post_reaction(post_id, _)) => space_id_of_post(post_id),
+			Call::SpaceFollows(pallet_space_follows::Call::follow_space(space_id)) => Some(*space_id),
+			Call::SpaceFollows(pallet_space_follows::Call::unfollow_space(space_id)) => Some(*space_id),
+			Call::Spaces(pallet_spaces::Call::update_space(space_id, _)) => Some(*space_id),
+			Call::SpaceOwnership(pallet_space_ownership::Call::transfer_space_ownership(space_id, ..)) => Some(*space_id),
+			Call::SpaceOwnership(pallet_space_ownership::Call::cancel_pending_ownership(space_id)) => Some(*space_id),
+			Call::Roles(pallet_roles::Call::create_role(space_id, ..)) => Some(*space_id),
+			Call::Roles(pallet_roles::Call::create_role_from_template(space_id, _)) => Some(*space_id),
+			Call::Roles(pallet_roles::Call::clone_roles(_, to_space)) => Some(*to_space),
+			Call::Roles(pallet_roles::Call::update_role(role_id, _)) => space_id_of_role(role_id),
+			Call::Roles(pallet_roles::Call::delete_role(role_id)) => space_id_of_role(role_id),
+			Call::Roles(pallet_roles::Call::continue_delete_role(role_id)) => space_id_of_role(role_id),
+			Call::Roles(pallet_roles::Call::grant_role(role_id, _)) => space_id_of_role(role_id),
+			Call::Roles(pallet_roles::Call::revoke_role(role_id, _)) => space_id_of_role(role_id),
+			_ => None,
+		}
+	}
+}
+
+pub struct RawContentOfCallDetector;
+impl pallet_spaces::CallRawContentDetector<Call> for RawContentOfCallDetector {
+	fn has_raw_content(c: &Call) -> bool {
+		match c {
+			Call::Posts(pallet_posts::Call::create_post(_, _, content, ..)) => content.is_raw(),
+			Call::Posts(pallet_posts::Call::update_post(_, update)) =>
+				update.content.as_ref().map_or(false, |content| content.is_raw()),
+			_ => false,
+		}
+	}
+}
+
+parameter_types! {
+	pub const MaxSpaceHistoryRecords: u16 = 50;
+}
 
-impl pallet_space_history::Trait for Runtime {}
+impl pallet_space_history::Trait for Runtime {
+	type MaxHistoryRecords = MaxSpaceHistoryRecords;
+}
+
+parameter_types! {
+	pub const MinSpaceOwners: u16 = 1;
+	pub const MaxSpaceOwners: u16 = 1_000;
+	pub const MaxChangeNotesLength: u16 = 1_024;
+	pub const BlocksToLive: BlockNumber = 7 * DAYS;
+	pub const DeleteExpiredChangesPeriod: BlockNumber = 3 * HOURS;
+	pub const ProposalDepositPerByte: Balance = 10 * MILLICENTS;
+}
+
+pub struct SpaceMultiOwnershipCallFilter;
+impl Filter<Call> for SpaceMultiOwnershipCallFilter {
+	fn filter(c: &Call) -> bool {
+		match *c {
+			Call::Spaces(..) => true,
+			Call::SpaceOwnership(..) => true,
+			Call::Roles(..) => true,
+			Call::Posts(..) => true,
+			Call::PostOwnership(..) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_space_multi_ownership::Trait for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type CallFilter = SpaceMultiOwnershipCallFilter;
+	type CallSpaceResolver = SpaceOfCallResolver;
+	type ProposalDepositPerByte = ProposalDepositPerByte;
+	type Spaces = SpaceOwnership;
+	type MinSpaceOwners = MinSpaceOwners;
+	type MaxSpaceOwners = MaxSpaceOwners;
+	type MaxChangeNotesLength = MaxChangeNotesLength;
+	type BlocksToLive = BlocksToLive;
+	type DeleteExpiredChangesPeriod = DeleteExpiredChangesPeriod;
+}
 
 pub struct BaseFilter;
 impl Filter<Call> for BaseFilter {
@@ -519,9 +662,20 @@ impl pallet_subscriptions::Trait for Runtime {
 }
 */
 
+parameter_types! {
+	pub const DripRequestDifficulty: u32 = 16;
+	pub const MaxPendingDripRequests: u16 = 1_000;
+	pub const MaxDripsPerBlock: u16 = 20;
+	pub const DripRequestUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 impl pallet_faucets::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type DripRequestDifficulty = DripRequestDifficulty;
+	type MaxPendingDripRequests = MaxPendingDripRequests;
+	type MaxDripsPerBlock = MaxDripsPerBlock;
+	type DripRequestUnsignedPriority = DripRequestUnsignedPriority;
 }
 
 construct_runtime!(
@@ -546,21 +700,24 @@ construct_runtime!(
 		Permissions: pallet_permissions::{Module, Call},
 		Posts: pallet_posts::{Module, Call, Storage, Event<T>},
 		PostHistory: pallet_post_history::{Module, Storage},
+		PostOwnership: pallet_post_ownership::{Module, Call, Storage, Event<T>},
 		ProfileFollows: pallet_profile_follows::{Module, Call, Storage, Event<T>},
 		Profiles: pallet_profiles::{Module, Call, Storage, Event<T>},
 		ProfileHistory: pallet_profile_history::{Module, Storage},
 		Reactions: pallet_reactions::{Module, Call, Storage, Event<T>},
 		Roles: pallet_roles::{Module, Call, Storage, Event<T>},
-		Scores: pallet_scores::{Module, Call, Storage, Event<T>},
+		Scores: pallet_scores::{Module, Call, Storage, Event<T>, Config},
 		SpaceFollows: pallet_space_follows::{Module, Call, Storage, Event<T>},
 		SpaceHistory: pallet_space_history::{Module, Storage},
+		SpaceMultiOwnership: pallet_space_multi_ownership::{Module, Call, Storage, Event<T>},
 		SpaceOwnership: pallet_space_ownership::{Module, Call, Storage, Event<T>},
 		Spaces: pallet_spaces::{Module, Call, Storage, Event<T>, Config<T>},
 		Utils: pallet_utils::{Module, Storage, Event<T>, Config<T>},
+		Notifications: pallet_notifications::{Module, Call, Storage, Event<T>},
 
 		// New experimental pallets. Not recommended to use in production yet.
 
-		Faucets: pallet_faucets::{Module, Call, Storage, Event<T>},
+		Faucets: pallet_faucets::{Module, Call, Storage, Event<T>, ValidateUnsigned},
 		// SessionKeys: pallet_session_keys::{Module, Call, Storage, Event<T>},
 		// Moderation: pallet_moderation::{Module, Call, Storage, Event<T>},
 		// Donations: pallet_donations::{Module, Call, Storage, Event<T>},
@@ -776,6 +933,10 @@ impl_runtime_apis! {
     	fn filter_followed_space_ids(account: AccountId, space_ids: Vec<SpaceId>) -> Vec<SpaceId> {
     		SpaceFollows::filter_followed_space_ids(account, space_ids)
     	}
+
+    	fn get_space_followers(space_id: SpaceId, offset: u64, limit: u16) -> Vec<AccountId> {
+    		SpaceFollows::get_space_followers(space_id, offset, limit)
+    	}
     }
 
 	impl spaces_runtime_api::SpacesApi<Block, AccountId, BlockNumber> for Runtime
@@ -815,6 +976,10 @@ impl_runtime_apis! {
         fn get_next_space_id() -> SpaceId {
         	Spaces::get_next_space_id()
         }
+
+        fn get_top_spaces(offset: u64, limit: u16) -> Vec<FlatSpace<AccountId, BlockNumber>> {
+        	Spaces::get_top_spaces(offset, limit)
+        }
     }
 
     impl posts_runtime_api::PostsApi<Block, AccountId, BlockNumber> for Runtime
@@ -863,9 +1028,56 @@ impl_runtime_apis! {
 			Posts::get_next_post_id()
 		}
 
+		fn get_post_ids_by_owner(account: AccountId) -> Vec<PostId> {
+			Posts::get_post_ids_by_owner(account)
+		}
+
+		fn get_posts_by_owner(
+			account: AccountId,
+			kind_filter: Vec<FlatPostKind>,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_posts_by_owner(account, kind_filter, offset, limit)
+		}
+
 		fn get_feed(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>> {
 			Posts::get_feed(account, offset, limit)
 		}
+
+		fn get_home_feed(
+			account: AccountId,
+			options: FeedOptions,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_home_feed(account, options, offset, limit)
+		}
+
+		fn get_top_posts_by_space_id(space_id: SpaceId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_top_posts_by_space_id(space_id, offset, limit)
+		}
+
+		fn get_hot_posts(
+			space_id: Option<SpaceId>,
+			kind_filter: Vec<FlatPostKind>,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_hot_posts(space_id, kind_filter, offset, limit)
+		}
+
+		fn get_hot_feed(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_hot_feed(account, offset, limit)
+		}
+
+		fn get_posts_by_tag(tag: Vec<u8>, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_posts_by_tag(tag, offset, limit)
+		}
+
+		fn get_mentions(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPostMention<AccountId, BlockNumber>> {
+			Posts::get_mentions(account, offset, limit)
+		}
     }
 
 	impl profile_follows_runtime_api::ProfileFollowsApi<Block, AccountId> for Runtime
@@ -873,6 +1085,14 @@ impl_runtime_apis! {
     	fn filter_followed_accounts(account: AccountId, maybe_following: Vec<AccountId>) -> Vec<AccountId> {
     		ProfileFollows::filter_followed_accounts(account, maybe_following)
     	}
+
+    	fn get_account_followers(account: AccountId, offset: u64, limit: u16) -> Vec<AccountId> {
+    		ProfileFollows::get_account_followers(account, offset, limit)
+    	}
+
+    	fn get_accounts_followed_by_account(account: AccountId, offset: u64, limit: u16) -> Vec<AccountId> {
+    		ProfileFollows::get_accounts_followed_by_account(account, offset, limit)
+    	}
     }
 
 	impl profiles_runtime_api::ProfilesApi<Block, AccountId, BlockNumber> for Runtime
@@ -882,6 +1102,13 @@ impl_runtime_apis! {
         ) -> Vec<FlatSocialAccount<AccountId, BlockNumber>> {
         	Profiles::get_social_accounts_by_ids(account_ids)
         }
+
+		fn get_top_accounts_by_reputation(
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatSocialAccount<AccountId, BlockNumber>> {
+			Profiles::get_top_accounts_by_reputation(offset, limit)
+		}
 	}
 
     impl reactions_runtime_api::ReactionsApi<Block, AccountId, BlockNumber> for Runtime
@@ -906,7 +1133,7 @@ impl_runtime_apis! {
 		}
     }
 
-	impl roles_runtime_api::RolesApi<Block, AccountId> for Runtime
+	impl roles_runtime_api::RolesApi<Block, AccountId, BlockNumber> for Runtime
 	{
 		fn get_space_permissions_by_account(
 			account: AccountId,
@@ -922,5 +1149,81 @@ impl_runtime_apis! {
         fn get_space_ids_for_account_with_any_role(account_id: AccountId) -> Vec<SpaceId> {
 			Roles::get_space_ids_for_account_with_any_role(account_id)
         }
+
+		fn get_roles_by_space_id(
+			space_id: SpaceId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatRole<AccountId, BlockNumber>> {
+			Roles::get_roles_by_space_id(space_id, offset, limit)
+		}
+
+		fn get_users_by_role_id(role_id: RoleId, offset: u64, limit: u16) -> Vec<User<AccountId>> {
+			Roles::get_users_by_role_id(role_id, offset, limit)
+		}
+
+		fn get_roles_by_user_in_space(
+			user: User<AccountId>,
+			space_id: SpaceId,
+		) -> Vec<FlatRole<AccountId, BlockNumber>> {
+			Roles::get_roles_by_user_in_space(user, space_id)
+		}
+	}
+
+	impl post_history_runtime_api::PostHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_post_edit_history(
+			post_id: PostId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatPostHistoryRecord<AccountId, BlockNumber>> {
+			PostHistory::get_post_edit_history(post_id, offset, limit)
+		}
+	}
+
+	impl notifications_runtime_api::NotificationsApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_notifications(
+			account: AccountId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatNotification<AccountId, BlockNumber>> {
+			Notifications::get_notifications(account, offset, limit)
+		}
+
+		fn get_unread_notifications_count(account: AccountId) -> u64 {
+			Notifications::get_unread_notifications_count(account)
+		}
+	}
+
+	impl space_history_runtime_api::SpaceHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_space_edit_history(
+			space_id: SpaceId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatSpaceHistoryRecord<AccountId, BlockNumber>> {
+			SpaceHistory::get_space_edit_history(space_id, offset, limit)
+		}
+	}
+
+	impl profile_history_runtime_api::ProfileHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_profile_edit_history(
+			account: AccountId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatProfileHistoryRecord<AccountId, BlockNumber>> {
+			ProfileHistory::get_profile_edit_history(account, offset, limit)
+		}
+	}
+
+	impl space_ownership_runtime_api::SpaceOwnershipApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_pending_transfers_to_account(
+			account: AccountId,
+		) -> Vec<FlatPendingSpaceOwnershipTransfer<AccountId, BlockNumber>> {
+			SpaceOwnership::get_pending_transfers_to_account(account)
+		}
 	}
 }
@@ -8,8 +8,10 @@ const pallets = [
   "donations",
   "faucets",
   "moderation",
+  "notifications",
   "permissions",
   "post-history",
+  "post-ownership",
   "posts",
   "profile-follows",
   "profile-history",
@@ -20,6 +22,7 @@ const pallets = [
   "session-keys",
   "space-follows",
   "space-history",
+  "space-multi-ownership",
   "space-ownership",
   "spaces",
   "subscriptions",